@@ -163,11 +163,11 @@ where N: Eq + NodeTrait {
     }
 
     pub fn with_capacity(num_nodes: usize, num_edges: usize) -> Self {
-        let avg_edges = num_edges / num_nodes; 
+        let avg_edges = num_edges / num_nodes.max(1);
 
         ConcurrentDiGraph {
-            outgoing_edges: DashMap::with_capacity_and_shard_amount(num_nodes, num_nodes.next_power_of_two()),//DashMap::with_capacity(num_nodes),
-            incoming_edges: DashMap::with_capacity_and_shard_amount(num_nodes, num_nodes.next_power_of_two()),//DashMap::with_capacity(num_nodes),
+            outgoing_edges: DashMap::with_capacity_and_shard_amount(num_nodes, num_nodes.next_power_of_two().max(2)),//DashMap::with_capacity(num_nodes),
+            incoming_edges: DashMap::with_capacity_and_shard_amount(num_nodes, num_nodes.next_power_of_two().max(2)),//DashMap::with_capacity(num_nodes),
             avg_edges
         }
     }
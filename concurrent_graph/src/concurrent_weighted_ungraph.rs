@@ -0,0 +1,107 @@
+use dashmap::DashMap;
+use std::collections::HashMap;
+use rayon::prelude::*;
+
+use crate::{ConcurrentUnGraph, GraphTrait, NodeTrait};
+
+/// Adjacency list with a weight per edge
+#[derive(Clone, Debug)]
+pub struct ConcurrentWeightedUnGraph<N: NodeTrait, W> {
+    adj_list: DashMap<N, HashMap<N, W>>,
+}
+
+impl<N, W> Default for ConcurrentWeightedUnGraph<N, W>
+where N: Eq + NodeTrait, W: Copy + PartialOrd + Send + Sync {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, W> ConcurrentWeightedUnGraph<N, W>
+where N: Eq + NodeTrait, W: Copy + PartialOrd + Send + Sync {
+    /// Create a new graph
+    pub fn new() -> Self {
+        ConcurrentWeightedUnGraph {
+            adj_list: DashMap::new(),
+        }
+    }
+
+    pub fn with_capacity(num_nodes: usize) -> Self {
+        ConcurrentWeightedUnGraph {
+            adj_list: DashMap::with_capacity_and_shard_amount(num_nodes, num_nodes.next_power_of_two().max(2)),
+        }
+    }
+
+    pub fn from_edges(edges: &[(N, N, W)]) -> Self {
+        let graph = Self::new();
+
+        for &(a, b, w) in edges {
+            graph.add_edge(a, b, w);
+        }
+
+        graph
+    }
+
+    pub fn nodes(&self) -> Vec<N> {
+        self.adj_list
+            .par_iter()
+            .map(|entry| *entry.key())
+            .collect()
+    }
+
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.adj_list.len()
+    }
+
+    /// Every edge is counted from both ends, as in ConcurrentUnGraph
+    pub fn edge_count(&self) -> usize {
+        self.adj_list.par_iter()
+            .map(|entry| entry.value().len())
+            .sum()
+    }
+
+    /// Add a weighted edge between two nodes; a parallel edge only lowers the weight, self-loops are allowed
+    pub fn add_edge(&self, a: N, b: N, w: W) {
+        for (u, v) in [(a, b), (b, a)] {
+            let mut neighbors = self.adj_list.entry(u).or_default();
+            let weight = neighbors.entry(v).or_insert(w);
+            if w < *weight {
+                *weight = w;
+            }
+        }
+    }
+
+    /// Get neighbors of a node with the weights of the edges reaching them
+    pub fn neighbors(&self, node: N) -> HashMap<N, W> {
+        match self.adj_list.get(&node) {
+            Some(neighbors) => neighbors.clone(),
+            None => HashMap::new()
+        }
+    }
+
+    /// Weight of the edge between two nodes ~ O(1)
+    pub fn weight(&self, a: N, b: N) -> Option<W> {
+        self.adj_list.get(&a).and_then(|neighbors| neighbors.get(&b).copied())
+    }
+
+    /// Get the adjacency list
+    #[inline]
+    pub fn get_neighborhoods(&self) -> &DashMap<N, HashMap<N, W>> {
+        &self.adj_list
+    }
+
+    /// Same graph without the weights
+    pub fn unweighted(&self) -> ConcurrentUnGraph<N> {
+        let graph = ConcurrentUnGraph::with_capacity(self.node_count().max(1), self.edge_count());
+
+        self.adj_list.par_iter().for_each(|entry| {
+            graph.add_node(*entry.key());
+            for &v in entry.value().keys() {
+                graph.add_edge(*entry.key(), v);
+            }
+        });
+
+        graph
+    }
+}
@@ -6,5 +6,8 @@ pub use crate::concurrent_digraph::ConcurrentDiGraph;
 mod concurrent_ungraph;
 pub use crate::concurrent_ungraph::ConcurrentUnGraph;
 
+mod concurrent_weighted_ungraph;
+pub use crate::concurrent_weighted_ungraph::ConcurrentWeightedUnGraph;
+
 mod traits;
 pub use crate::traits::util_traits::{NodeTrait, GraphTrait};
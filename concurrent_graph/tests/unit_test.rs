@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests{
     use std::collections::HashSet;
-    use concurrent_graph::{ConcurrentDiGraph, ConcurrentUnGraph};
+    use concurrent_graph::ConcurrentDiGraph;
+    use concurrent_graph::ConcurrentWeightedUnGraph;
     use concurrent_graph::GraphTrait;
 
     #[test]
@@ -24,7 +25,7 @@ mod tests{
         assert_eq!(g.edge_count(), 10);
 
         assert!(g.contains_edge(0, 1));
-        assert!(g.contains_edge(1, 0) == false);
+        assert!(!g.contains_edge(1, 0));
     }
 
     #[test]
@@ -146,6 +147,35 @@ mod tests{
 
     }
 
+    #[test]
+    fn weighted_keeps_cheapest_edge(){
+        let g = ConcurrentWeightedUnGraph::new();
+        g.add_edge(0, 1, 3.0);
+        g.add_edge(1, 0, 2.0);
+        g.add_edge(0, 1, 5.0);
+        g.add_edge(1, 2, 1.0);
+
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 4);
+        assert_eq!(g.weight(0, 1), Some(2.0));
+        assert_eq!(g.weight(1, 0), Some(2.0));
+        assert_eq!(g.weight(0, 2), None);
+
+        let u = g.unweighted();
+        assert_eq!(u.node_count(), 3);
+        assert!(u.contains_edge(2, 1));
+    }
+
+    #[test]
+    fn weighted_with_small_capacity(){
+        for n in [0, 1] {
+            let g = ConcurrentWeightedUnGraph::with_capacity(n);
+            assert_eq!(g.node_count(), 0);
+            g.add_edge(0, 1, 1.0);
+            assert_eq!(g.weight(1, 0), Some(1.0));
+        }
+    }
+
     //problem: removing nodes DO NOT remove edges
     /*
     #[test]
@@ -1,68 +1,158 @@
 pub mod prelude {
     use std::{
-        fmt::Debug,
+        fmt::{Debug, Display},
         fs::File,
-        io::{self, BufRead, Error},
+        io::{self, BufRead, BufWriter, Error, ErrorKind, Write},
         str::FromStr,
     };
     
 
-    #[macro_export]
-    macro_rules! debug_println {
-        ($($arg:tt)*) => (if ::std::cfg!(debug_assertions) { ::std::println!($($arg)*); })
+    /// Read the edges of a MatrixMarket file or of a headerless edge list (see [`GraphFormat`]), extra columns are ignored
+    pub fn read_from_file<V>(filename: &str) -> Result<Vec<(V, V)>, Error>
+    where V: FromStr<Err: Debug>,
+    {
+        parse_lines(filename, "`a b`", |parts| match parts {
+            [a, b, ..] => Some((a.parse().ok()?, b.parse().ok()?)),
+            _ => None,
+        })
     }
 
-    pub fn read_from_file<V: FromStr>(filename: &str) -> Result<Vec<(V, V)>, Error>
-    where V: FromStr<Err: Debug>,
+    /// Read a `real`/`integer` MatrixMarket file, or an edge list with a value column, as (a, b, value) triples; NaN or infinite values are an error
+    pub fn read_weighted_from_file<V, W>(filename: &str) -> Result<Vec<(V, V, W)>, Error>
+    where
+        V: FromStr,
+        W: FromStr + Into<f64> + Copy,
     {
-        //let res = Vec::<(u8, u8)>::new();
-        let file = File::open(filename);
+        parse_lines(filename, "`a b value` with a finite value", |parts| match parts {
+            [a, b, w, ..] => {
+                let w: W = w.parse().ok()?;
+                w.into().is_finite().then_some((a.parse().ok()?, b.parse().ok()?, w))
+            }
+            _ => None,
+        })
+    }
 
-        match file {
-            Ok(f) => Ok(parse_file(f)),
-            Err(e) => Err(e),
+    /// Number of nodes declared by the `rows cols entries` line of a MatrixMarket file (the larger of rows and cols), `None` for an edge list
+    pub fn read_num_nodes(filename: &str) -> Result<Option<usize>, Error> {
+        let mut lines = io::BufReader::new(File::open(filename)?).lines();
+
+        match lines.next().transpose()? {
+            Some(header) if header.starts_with('%') && header.trim_start_matches('%').starts_with("MatrixMarket") => (),
+            _ => return Ok(None),
         }
+
+        for line in lines {
+            let line = line?;
+            let data = line.trim();
+            if data.is_empty() || data.starts_with('%') || data.starts_with('#') {
+                continue;
+            }
+
+            let size: Vec<usize> = data.split_whitespace().map(str::parse).collect::<Result<_, _>>()
+                .map_err(|_| Error::new(ErrorKind::InvalidData, format!("{filename}: expected `rows cols entries`, got {line:?}")))?;
+            return match size[..] {
+                [rows, cols, _] => Ok(Some(rows.max(cols))),
+                _ => Err(Error::new(ErrorKind::InvalidData, format!("{filename}: expected `rows cols entries`, got {line:?}"))),
+            };
+        }
+
+        Err(Error::new(ErrorKind::InvalidData, format!("{filename}: missing MatrixMarket size line")))
     }
 
-    fn parse_file<V: FromStr>(file: File) -> Vec<(V, V)>
-    where <V as FromStr>::Err: Debug,
+    /// Parse every data line of `filename`: files starting with a `%%MatrixMarket` (or `%MatrixMarket`) header have their size line skipped,
+    /// anything else is an edge list; empty lines and `%`/`#` comments are skipped, a malformed line is an error
+    fn parse_lines<T, F>(filename: &str, expected: &str, parse: F) -> Result<Vec<T>, Error>
+    where F: Fn(&[&str]) -> Option<T>,
     {
-        //let res = Vec::<(u8, u8)>::new();
-        let reader = io::BufReader::new(file);
-
-        let mut lines = reader.lines();
-        lines.next(); //skip first line
-
-        //let graph_info = lines.next().unwrap().unwrap();
-        let graph_info = match lines.next(){
-            Some(v) => v.unwrap(),
-            None => {
-                println!("Empty file");
-                std::process::exit(1);
-            }
+        let reader = io::BufReader::new(File::open(filename)?);
+        let malformed = |i: usize, line: &str, expected: &str| {
+            Error::new(ErrorKind::InvalidData, format!("{filename}:{}: expected {expected}, got {line:?}", i + 1))
         };
 
+        let mut items = Vec::new();
+        let mut size_line = false;
 
-        let graph_struct: Vec<_> = graph_info.split_whitespace().collect();
-        let n_edges = graph_struct[2].parse::<usize>().unwrap_or(0);
-        //println!("{:?}", n_edges);
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if i == 0 && line.starts_with('%') && line.trim_start_matches('%').starts_with("MatrixMarket") {
+                size_line = true;
+                continue;
+            }
 
-        let mut edges: Vec<(V, V)> = Vec::with_capacity(n_edges);
+            let data = line.trim();
+            if data.is_empty() || data.starts_with('%') || data.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = data.split_whitespace().collect();
 
-        //TODO: iterator over lines
-        for line in lines {
-            let str = line.ok().unwrap();
-            let parts: Vec<&str> = str.split_whitespace().collect();
-
-            let edge = (
-                parts[0].parse::<V>().unwrap(),
-                parts[1].parse::<V>().unwrap(),
-            );
-            edges.push(edge);
+            //`rows cols entries`
+            if size_line {
+                size_line = false;
+                match parts[..] {
+                    [_, _, entries] => items.reserve(entries.parse().map_err(|_| malformed(i, &line, "`rows cols entries`"))?),
+                    _ => return Err(malformed(i, &line, "`rows cols entries`")),
+                }
+                continue;
+            }
+
+            items.push(parse(&parts).ok_or_else(|| malformed(i, &line, expected))?);
+        }
+
+        if size_line {
+            return Err(Error::new(ErrorKind::InvalidData, format!("{filename}: missing MatrixMarket size line")));
         }
 
-        edges
+        Ok(items)
+    }
+
+
+    /// Formats graphs can be written to
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum GraphFormat {
+        /// `%%MatrixMarket` header + `nodes nodes edges` line, then one edge per line
+        MatrixMarket,
+        /// One `a b` edge per line, no header
+        EdgeList,
+    }
+
+    impl GraphFormat {
+        /// Guess the format from the file extension (`.mtx` => MatrixMarket)
+        pub fn from_path(filename: &str) -> Self {
+            if filename.ends_with(".mtx") {
+                GraphFormat::MatrixMarket
+            } else {
+                GraphFormat::EdgeList
+            }
+        }
     }
 
+    impl FromStr for GraphFormat {
+        type Err = String;
 
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "mtx" => Ok(GraphFormat::MatrixMarket),
+                "el" | "edgelist" => Ok(GraphFormat::EdgeList),
+                _ => Err(format!("unknown format {s:?} (expected mtx or el)")),
+            }
+        }
+    }
+
+    pub fn write_to_file<V: Display>(filename: &str, edges: &[(V, V)], num_nodes: usize, format: GraphFormat) -> Result<(), Error> {
+        let file = File::create(filename)?;
+        write_edges(BufWriter::new(file), edges, num_nodes, format)
+    }
+
+    pub fn write_edges<W: Write, V: Display>(mut out: W, edges: &[(V, V)], num_nodes: usize, format: GraphFormat) -> Result<(), Error> {
+        if format == GraphFormat::MatrixMarket {
+            writeln!(out, "%%MatrixMarket matrix coordinate pattern symmetric")?;
+            writeln!(out, "{} {} {}", num_nodes, num_nodes, edges.len())?;
+        }
+
+        for (a, b) in edges {
+            writeln!(out, "{} {}", a, b)?;
+        }
+
+        out.flush()
+    }
 }
@@ -0,0 +1,114 @@
+use getopts::Options;
+use graph_example::benchmark::parse_list;
+use graph_example::cracker::Variant;
+use graph_example::logging;
+use graph_example::worst_case::{worst_case, write_csv, write_json, Family};
+
+use std::{fs::File, io};
+
+use rayon::ThreadPoolBuilder;
+
+fn main() {
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("s", "sizes", "comma-separated #nodes (default 2^4, 2^5, ..., 2^12)", "64,128");
+    opts.optopt("", "families", "comma-separated families", "path,zigzag,caterpillar,comb");
+    opts.optopt("", "variants", "comma-separated variants", "base,ep,epos");
+    opts.optopt("n", "num_thread", "provide the number of threads to use", "0");
+    opts.optopt("o", "output", "write the report to a file instead of stdout", "FILEPATH");
+    opts.optopt("", "format", "output format (default csv)", "csv|json");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    //handle -n/--num_threads
+    let num_threads = match matches.opt_str("n") {
+        None => 0,  //let rayon decide
+        Some(v) => v.parse().unwrap(),
+    };
+
+    //setup parallelism
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()
+        .unwrap();
+
+    let sizes: Result<Vec<usize>, String> = match matches.opt_str("s") {
+        Some(v) => parse_list(&v),
+        None => Ok((4..=12).map(|e| 1 << e).collect()),
+    };
+    let families: Result<Vec<Family>, String> = match matches.opt_str("families") {
+        Some(v) => parse_list(&v),
+        None => Ok(Family::ALL.to_vec()),
+    };
+    let variants: Result<Vec<Variant>, String> = match matches.opt_str("variants") {
+        Some(v) => parse_list(&v),
+        None => Ok(Variant::ALL.to_vec()),
+    };
+
+    let (sizes, families, variants) = match (sizes, families, variants) {
+        (Ok(s), Ok(f), Ok(v)) => (s, f, v),
+        (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let mut rows = Vec::with_capacity(sizes.len() * families.len() * variants.len());
+    for &family in &families {
+        for &n in &sizes {
+            for &variant in &variants {
+                match worst_case(family, n, variant) {
+                    Ok(row) => rows.push(row),
+                    Err(e) => {
+                        eprintln!("{e}");
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    let out: Box<dyn io::Write> = match matches.opt_str("o") {
+        Some(path) => match File::create(&path) {
+            Ok(f) => Box::new(f),
+            Err(e) => {
+                eprintln!("Error creating {path}: {e}");
+                return;
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    let written = match matches.opt_str("format").as_deref() {
+        None | Some("csv") => write_csv(out, &rows),
+        Some("json") => write_json(out, &rows),
+        Some(other) => {
+            eprintln!("Unknown format {other:?}");
+            return;
+        }
+    };
+
+    if let Err(e) = written {
+        eprintln!("Error writing report: {e}");
+    }
+}
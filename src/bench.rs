@@ -0,0 +1,143 @@
+use getopts::{Matches, Options};
+use graph_example::benchmark::{
+    bench_file, parse_list, scaling, thread_sweep, write_csv, write_json, write_scaling_csv, write_scaling_json,
+    BenchConfig,
+};
+use graph_example::datasets::read_manifest;
+use graph_example::logging;
+
+use std::{fs::File, io, path::Path};
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static GLOBAL: graph_example::memory::CountingAllocator = graph_example::memory::CountingAllocator;
+
+fn main() {
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optmulti("f", "file", "graph to benchmark (repeatable)", "FILEPATH");
+    opts.optopt("m", "manifest", "benchmark every dataset listed in a manifest", "MANIFEST");
+    opts.optopt("n", "num_thread", "comma-separated thread counts, 0 = let rayon decide", "1,2,4");
+    opts.optopt("", "variants", "comma-separated variants", "base,ep,epos");
+    opts.optopt("w", "warmup", "unrecorded runs before measuring (default 1)", "N");
+    opts.optopt("r", "runs", "measured runs (default 5)", "N");
+    opts.optflag("", "scale", "sweep 1, 2, 4, ... threads and report speedup and efficiency");
+    opts.optopt("", "max_threads", "largest thread count of the --scale sweep (default: #cores)", "N");
+    opts.optopt("o", "output", "write results to a file instead of stdout", "FILEPATH");
+    opts.optopt("", "format", "output format (default csv)", "csv|json");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -f FILE [-f FILE ...] [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    let config = match bench_config(&matches) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    //datasets
+    let mut files = matches.opt_strs("f");
+    if let Some(manifest) = matches.opt_str("m") {
+        let manifest = Path::new(&manifest);
+        let dir = manifest.parent().unwrap_or(Path::new("."));
+
+        match read_manifest(manifest) {
+            Ok(datasets) => files.extend(datasets.iter().map(|d| d.path(dir).display().to_string())),
+            Err(e) => {
+                eprintln!("Error reading manifest: {e}");
+                return;
+            }
+        }
+    }
+
+    if files.is_empty() {
+        eprintln!("Please provide a filename or a manifest");
+        return;
+    }
+
+    let mut measurements = Vec::new();
+    for file in &files {
+        eprintln!("benchmarking {file}");
+
+        match bench_file(file, &config) {
+            Ok(m) => measurements.extend(m),
+            Err(e) => {
+                eprintln!("Error benchmarking {file}: {e}");
+                return;
+            }
+        }
+    }
+
+    let out: Box<dyn io::Write> = match matches.opt_str("o") {
+        Some(path) => match File::create(&path) {
+            Ok(f) => Box::new(f),
+            Err(e) => {
+                eprintln!("Error creating {path}: {e}");
+                return;
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    let scale = matches.opt_present("scale");
+    let written = match matches.opt_str("format").as_deref() {
+        None | Some("csv") if scale => write_scaling_csv(out, &scaling(&measurements)),
+        Some("json") if scale => write_scaling_json(out, &scaling(&measurements)),
+        None | Some("csv") => write_csv(out, &measurements),
+        Some("json") => write_json(out, &measurements),
+        Some(other) => {
+            eprintln!("Unknown format {other:?}");
+            return;
+        }
+    };
+
+    if let Err(e) = written {
+        eprintln!("Error writing results: {e}");
+    }
+}
+
+fn bench_config(matches: &Matches) -> Result<BenchConfig, String> {
+    let mut config = BenchConfig::default();
+
+    if let Some(v) = matches.opt_str("n") {
+        config.num_threads = parse_list(&v)?;
+    } else if matches.opt_present("scale") {
+        let max_threads = match matches.opt_str("max_threads") {
+            Some(v) => v.parse().map_err(|_| format!("invalid max_threads {v:?}"))?,
+            None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        };
+        config.num_threads = thread_sweep(max_threads);
+    }
+    if let Some(v) = matches.opt_str("variants") {
+        config.variants = parse_list(&v)?;
+    }
+    if let Some(v) = matches.opt_str("w") {
+        config.warmup = v.parse().map_err(|_| format!("invalid warmup {v:?}"))?;
+    }
+    if let Some(v) = matches.opt_str("r") {
+        config.runs = v.parse().map_err(|_| format!("invalid runs {v:?}"))?;
+    }
+
+    Ok(config)
+}
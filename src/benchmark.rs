@@ -0,0 +1,323 @@
+use std::{
+    fmt::Display,
+    io::{self, Error, Write},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use io_util::prelude::read_from_file;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::Serialize;
+
+use crate::cracker::Variant;
+use crate::memory::{is_counting, MemoryProfile};
+
+type V = u32;
+
+/// Phases timed by the benchmark, in output order
+pub const PHASES: [&str; 5] = ["load", "min_selection", "pruning", "seed_propagation", "total"];
+
+/// Durations of a single run
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    /// Reading the file + building the input graph
+    pub load: Duration,
+    pub min_selection: Duration,
+    pub pruning: Duration,
+    pub seed_propagation: Duration,
+    pub total: Duration,
+}
+
+impl Timings {
+    pub fn phase(&self, phase: &str) -> Duration {
+        match phase {
+            "load" => self.load,
+            "min_selection" => self.min_selection,
+            "pruning" => self.pruning,
+            "seed_propagation" => self.seed_propagation,
+            "total" => self.total,
+            _ => panic!("unknown phase {phase:?}"),
+        }
+    }
+}
+
+/// Single run of a variant on a file
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub timings: Timings,
+    /// Only with the counting allocator installed
+    pub memory: Option<MemoryProfile>,
+    pub nodes: usize,
+    pub edges: usize,
+}
+
+impl Run {
+    /// (peak live bytes, #allocations) of a phase of [`PHASES`]
+    pub fn memory(&self, phase: &str) -> Option<(usize, u64)> {
+        let memory = self.memory.as_ref()?;
+        // every `min_selection_{it}` / `pruning_{it}` of the run
+        let prefix = if phase == "total" { "" } else { phase };
+
+        let phases: Vec<_> = memory.matching(prefix).collect();
+        Some((
+            phases.iter().map(|p| p.peak_bytes).max().unwrap_or(0),
+            phases.iter().map(|p| p.allocations).sum(),
+        ))
+    }
+}
+
+/// Statistics (in milliseconds) of the repetitions of a phase
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    /// Sample standard deviation, 0 with a single run
+    pub stddev_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
+impl Summary {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_unstable_by(f64::total_cmp);
+
+        let n = ms.len();
+        if n == 0 {
+            return Summary { median_ms: 0.0, mean_ms: 0.0, stddev_ms: 0.0, min_ms: 0.0, max_ms: 0.0 };
+        }
+
+        let median_ms = if n % 2 == 1 { ms[n / 2] } else { (ms[n / 2 - 1] + ms[n / 2]) / 2.0 };
+        let mean_ms = ms.iter().sum::<f64>() / n as f64;
+        let stddev_ms = if n > 1 {
+            (ms.iter().map(|x| (x - mean_ms).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        Summary { median_ms, mean_ms, stddev_ms, min_ms: ms[0], max_ms: ms[n - 1] }
+    }
+}
+
+/// One row of the benchmark output
+#[derive(Clone, Debug, Serialize)]
+pub struct Measurement {
+    pub file: String,
+    pub nodes: usize,
+    pub edges: usize,
+    pub variant: &'static str,
+    pub num_threads: usize,
+    pub phase: &'static str,
+    pub runs: usize,
+    #[serde(flatten)]
+    pub summary: Summary,
+    /// Max over the runs of the peak live heap bytes, needs the `memory` feature
+    pub peak_bytes: Option<usize>,
+    /// Max over the runs of the #allocations, needs the `memory` feature
+    pub allocations: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BenchConfig {
+    pub variants: Vec<Variant>,
+    /// 0 => let rayon decide
+    pub num_threads: Vec<usize>,
+    /// Unrecorded runs before measuring
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            variants: Variant::ALL.to_vec(),
+            num_threads: vec![0],
+            warmup: 1,
+            runs: 5,
+        }
+    }
+}
+
+/// Parse a comma-separated list, e.g. `1,2,4` or `base,epos`
+pub fn parse_list<T>(s: &str) -> Result<Vec<T>, String>
+where T: FromStr, <T as FromStr>::Err: Display,
+{
+    s.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse::<T>().map_err(|e| format!("invalid value {item:?}: {e}")))
+        .collect()
+}
+
+/// Build a dedicated pool: the global one can only be configured once
+pub fn thread_pool(num_threads: usize) -> Result<ThreadPool, Error> {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(Error::other)
+}
+
+/// Load `file` and run `variant` on it, timing every phase
+pub fn run_once(file: &str, variant: Variant) -> Result<Run, Error> {
+    let mut memory = is_counting().then(MemoryProfile::start);
+    let start = Instant::now();
+
+    let edges = read_from_file::<V>(file)?;
+    let graph = variant.graph(&edges);
+    let load = start.elapsed();
+    if let Some(memory) = memory.as_mut() {
+        memory.phase("load");
+    }
+
+    let res = variant.crack_observed(&graph, &mut memory);
+    let total = start.elapsed();
+
+    let timings = Timings {
+        load,
+        min_selection: res.min_selection,
+        pruning: res.pruning,
+        seed_propagation: res.seed_propagation,
+        total,
+    };
+
+    Ok(Run { timings, memory, nodes: graph.node_count(), edges: edges.len() })
+}
+
+/// Time every (variant, #threads) combination of `config` on `file`
+pub fn bench_file(file: &str, config: &BenchConfig) -> Result<Vec<Measurement>, Error> {
+    let mut measurements = Vec::with_capacity(config.variants.len() * config.num_threads.len() * PHASES.len());
+
+    for &num_threads in &config.num_threads {
+        let pool = thread_pool(num_threads)?;
+
+        for &variant in &config.variants {
+            let runs = pool.install(|| -> Result<_, Error> {
+                for _ in 0..config.warmup {
+                    run_once(file, variant)?;
+                }
+
+                (0..config.runs).map(|_| run_once(file, variant)).collect::<Result<Vec<_>, _>>()
+            })?;
+
+            let (nodes, edges) = runs.first().map(|r| (r.nodes, r.edges)).unwrap_or_default();
+
+            for phase in PHASES {
+                let samples: Vec<Duration> = runs.iter().map(|r| r.timings.phase(phase)).collect();
+                let memory: Option<Vec<(usize, u64)>> = runs.iter().map(|r| r.memory(phase)).collect();
+
+                measurements.push(Measurement {
+                    file: file.to_string(),
+                    nodes,
+                    edges,
+                    variant: variant.name(),
+                    num_threads: pool.current_num_threads(),
+                    phase,
+                    runs: samples.len(),
+                    summary: Summary::new(&samples),
+                    peak_bytes: memory.as_ref().and_then(|m| m.iter().map(|x| x.0).max()),
+                    allocations: memory.as_ref().and_then(|m| m.iter().map(|x| x.1).max()),
+                });
+            }
+        }
+    }
+
+    Ok(measurements)
+}
+
+/// Thread counts 1, 2, 4, ... up to `max` (always included)
+pub fn thread_sweep(max: usize) -> Vec<usize> {
+    let max = max.max(1);
+    let mut sweep: Vec<usize> = std::iter::successors(Some(1), |n| Some(n * 2))
+        .take_while(|&n| n < max)
+        .collect();
+    sweep.push(max);
+
+    sweep
+}
+
+/// Scaling of a phase at a given #threads w.r.t. the run with the fewest threads
+#[derive(Clone, Debug, Serialize)]
+pub struct Scaling {
+    pub file: String,
+    pub nodes: usize,
+    pub edges: usize,
+    pub variant: &'static str,
+    pub phase: &'static str,
+    pub num_threads: usize,
+    pub median_ms: f64,
+    /// T(baseline) / T(num_threads)
+    pub speedup: f64,
+    /// speedup / (num_threads / baseline threads)
+    pub efficiency: f64,
+}
+
+/// Compute speedup and parallel efficiency of every (file, variant, phase) group
+pub fn scaling(measurements: &[Measurement]) -> Vec<Scaling> {
+    let mut res = Vec::with_capacity(measurements.len());
+
+    for m in measurements {
+        let baseline = measurements
+            .iter()
+            .filter(|b| b.file == m.file && b.variant == m.variant && b.phase == m.phase)
+            .min_by_key(|b| b.num_threads)
+            .unwrap_or(m);
+
+        let speedup = if m.summary.median_ms > 0.0 { baseline.summary.median_ms / m.summary.median_ms } else { 0.0 };
+        let efficiency = speedup * baseline.num_threads as f64 / m.num_threads as f64;
+
+        res.push(Scaling {
+            file: m.file.clone(),
+            nodes: m.nodes,
+            edges: m.edges,
+            variant: m.variant,
+            phase: m.phase,
+            num_threads: m.num_threads,
+            median_ms: m.summary.median_ms,
+            speedup,
+            efficiency,
+        });
+    }
+
+    res
+}
+
+pub fn write_csv<W: Write>(mut out: W, measurements: &[Measurement]) -> io::Result<()> {
+    writeln!(out, "file,nodes,edges,variant,num_threads,phase,runs,median_ms,mean_ms,stddev_ms,min_ms,max_ms,peak_bytes,allocations")?;
+
+    let optional = |v: Option<u64>| v.map(|v| v.to_string()).unwrap_or_default();
+
+    for m in measurements {
+        let s = &m.summary;
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{},{}",
+            m.file, m.nodes, m.edges, m.variant, m.num_threads, m.phase, m.runs,
+            s.median_ms, s.mean_ms, s.stddev_ms, s.min_ms, s.max_ms,
+            optional(m.peak_bytes.map(|b| b as u64)), optional(m.allocations)
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn write_json<W: Write>(out: W, measurements: &[Measurement]) -> io::Result<()> {
+    serde_json::to_writer_pretty(out, measurements).map_err(Error::from)
+}
+
+pub fn write_scaling_csv<W: Write>(mut out: W, scaling: &[Scaling]) -> io::Result<()> {
+    writeln!(out, "file,nodes,edges,variant,phase,num_threads,median_ms,speedup,efficiency")?;
+
+    for s in scaling {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{:.3},{:.3},{:.3}",
+            s.file, s.nodes, s.edges, s.variant, s.phase, s.num_threads, s.median_ms, s.speedup, s.efficiency
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn write_scaling_json<W: Write>(out: W, scaling: &[Scaling]) -> io::Result<()> {
+    serde_json::to_writer_pretty(out, scaling).map_err(Error::from)
+}
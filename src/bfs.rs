@@ -0,0 +1,80 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+use concurrent_graph::{GraphTrait, NodeTrait};
+use rayon::prelude::*;
+
+/// Not reached yet
+pub const UNSEEN: usize = usize::MAX;
+
+/// Nodes of a connected component relabelled `0..n` in the order of `members`, and their sorted neighbor lists (self-loops included)
+pub fn dense<G, V>(graph: &G, members: &[V]) -> (HashMap<V, usize>, Vec<Vec<usize>>)
+where
+    G: GraphTrait<V> + Sync,
+    V: NodeTrait,
+{
+    let index: HashMap<V, usize> = members.par_iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let adjacency = members
+        .par_iter()
+        .map(|&v| {
+            let mut neighbors: Vec<usize> = graph.outgoing_edges(v).iter().map(|u| index[u]).collect();
+            neighbors.sort_unstable();
+            neighbors
+        })
+        .collect();
+
+    (index, adjacency)
+}
+
+/// BFS tree of `0..n`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bfs {
+    /// Parent of every node, the root is its own parent
+    pub parent: Vec<usize>,
+    pub level: Vec<usize>,
+    /// Nodes of every level, sorted
+    pub levels: Vec<Vec<usize>>,
+}
+
+/// Level-synchronous BFS from `root`: every frontier is expanded in parallel and a new node takes its smallest parent,
+/// so the tree does not depend on the schedule
+pub fn bfs(adjacency: &[Vec<usize>], root: usize) -> Bfs {
+    let parent: Vec<AtomicUsize> = (0..adjacency.len()).into_par_iter().map(|_| AtomicUsize::new(UNSEEN)).collect();
+    let level: Vec<AtomicUsize> = (0..adjacency.len()).into_par_iter().map(|_| AtomicUsize::new(UNSEEN)).collect();
+    parent[root].store(root, Relaxed);
+    level[root].store(0, Relaxed);
+
+    let mut levels = vec![vec![root]];
+    loop {
+        let frontier = &levels[levels.len() - 1];
+
+        frontier.par_iter().for_each(|&u| {
+            for &w in adjacency[u].iter().filter(|&&w| level[w].load(Relaxed) == UNSEEN) {
+                parent[w].fetch_min(u, Relaxed);
+            }
+        });
+        let mut next: Vec<usize> = frontier
+            .par_iter()
+            .flat_map_iter(|&u| {
+                let (level, parent) = (&level, &parent);
+                adjacency[u].iter().copied().filter(move |&w| level[w].load(Relaxed) == UNSEEN && parent[w].load(Relaxed) == u)
+            })
+            .collect();
+        if next.is_empty() {
+            break;
+        }
+
+        next.par_sort_unstable();
+        let depth = levels.len();
+        next.par_iter().for_each(|&w| level[w].store(depth, Relaxed));
+        levels.push(next);
+    }
+
+    Bfs {
+        parent: parent.into_iter().map(AtomicUsize::into_inner).collect(),
+        level: level.into_iter().map(AtomicUsize::into_inner).collect(),
+        levels,
+    }
+}
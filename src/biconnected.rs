@@ -0,0 +1,206 @@
+use std::{
+    fmt::{self, Debug},
+    fs::File,
+    io::{self, BufWriter, Error, Write},
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+use concurrent_graph::{GraphTrait, NodeTrait};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::bfs::{bfs, dense, Bfs};
+use crate::components::Components;
+use crate::cracker::{InputGraph, Variant};
+
+/// 2-connectivity of a connected component; edges are (a, b) with a < b, everything is sorted
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Biconnectivity<V> {
+    pub seed: V,
+    pub nodes: usize,
+    /// Edges of every biconnected component
+    pub blocks: Vec<Vec<(V, V)>>,
+    pub articulation_points: Vec<V>,
+    pub bridges: Vec<(V, V)>,
+}
+
+/// Biconnected components, articulation points and bridges of every connected component, in component order.
+/// Components run in parallel, and each one with Tarjan–Vishkin on a parallel BFS tree: every step is a parallel pass
+/// over the nodes, the edges or one BFS level, and the blocks are the connected components of an auxiliary graph, found by CRACKER
+pub fn biconnectivity<V: NodeTrait + Debug>(graph: &InputGraph<V>, components: &Components<V>) -> Vec<Biconnectivity<V>> {
+    match graph {
+        InputGraph::Undirected(g) => search(g, components),
+        InputGraph::Directed(g) => search(g, components),
+    }
+}
+
+fn search<G, V>(graph: &G, components: &Components<V>) -> Vec<Biconnectivity<V>>
+where
+    G: GraphTrait<V> + Sync,
+    V: NodeTrait + Debug,
+{
+    (0..components.len())
+        .into_par_iter()
+        .map(|c| component(graph, components.seed(c), components.members(c)))
+        .collect()
+}
+
+fn atomics(n: usize) -> Vec<AtomicUsize> {
+    (0..n).into_par_iter().map(|_| AtomicUsize::new(0)).collect()
+}
+
+fn values(atomics: Vec<AtomicUsize>) -> Vec<usize> {
+    atomics.into_iter().map(AtomicUsize::into_inner).collect()
+}
+
+fn component<G, V>(graph: &G, seed: V, members: &[V]) -> Biconnectivity<V>
+where
+    G: GraphTrait<V> + Sync,
+    V: NodeTrait + Debug,
+{
+    let (index, mut adjacency) = dense(graph, members);
+    adjacency.par_iter_mut().enumerate().for_each(|(v, neighbors)| neighbors.retain(|&w| w != v));
+
+    let root = index[&seed];
+    let Bfs { parent, levels, .. } = bfs(&adjacency, root);
+    let n = members.len();
+    let edge = |a: usize, b: usize| (members[a].min(members[b]), members[a].max(members[b]));
+    let is_tree = |v: usize, w: usize| parent[w] == v || parent[v] == w;
+
+    // children of every node, sorted
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for v in (0..n).filter(|&v| v != root) {
+        children[parent[v]].push(v);
+    }
+
+    // subtree sizes, bottom-up one level at a time
+    let size = atomics(n);
+    for level in levels.iter().rev() {
+        level.par_iter().for_each(|&v| size[v].store(1 + children[v].iter().map(|&c| size[c].load(Relaxed)).sum::<usize>(), Relaxed));
+    }
+    let size = values(size);
+
+    // preorder numbers, top-down: a subtree is the interval [pre, pre + size)
+    let pre = atomics(n);
+    for level in &levels {
+        level.par_iter().for_each(|&v| {
+            let mut next = pre[v].load(Relaxed) + 1;
+            for &c in &children[v] {
+                pre[c].store(next, Relaxed);
+                next += size[c];
+            }
+        });
+    }
+    let pre = &values(pre);
+    let is_ancestor = |a: usize, b: usize| pre[a] <= pre[b] && pre[b] < pre[a] + size[a];
+
+    // lowest and highest preorder number reached from a subtree by one non-tree edge, bottom-up
+    let low = atomics(n);
+    let high = atomics(n);
+    for level in levels.iter().rev() {
+        level.par_iter().for_each(|&v| {
+            let reached = adjacency[v].iter().filter(|&&w| !is_tree(v, w)).map(|&w| pre[w]);
+            let (mut l, mut h) = reached.fold((pre[v], pre[v]), |(l, h), p| (l.min(p), h.max(p)));
+            for &c in &children[v] {
+                l = l.min(low[c].load(Relaxed));
+                h = h.max(high[c].load(Relaxed));
+            }
+            low[v].store(l, Relaxed);
+            high[v].store(h, Relaxed);
+        });
+    }
+    let (low, high) = (values(low), values(high));
+
+    // auxiliary graph on the tree edges, named by their child: two tree edges share a block iff they are connected in it
+    let crossing = (0..n).into_par_iter().flat_map_iter(|v| {
+        adjacency[v].iter().filter(move |&&w| v < w && !is_tree(v, w) && !is_ancestor(v, w) && !is_ancestor(w, v)).map(move |&w| (v, w))
+    });
+    let escaping = (0..n).into_par_iter().filter_map(|v| {
+        let p = parent[v];
+        (v != root && p != root && (low[v] < pre[p] || high[v] >= pre[p] + size[p])).then_some((v, p))
+    });
+    let auxiliary: Vec<(usize, usize)> = crossing.chain(escaping).collect();
+    let tree_edges: Vec<usize> = (0..n).filter(|&v| v != root).collect();
+    let block = Variant::Ep.crack(&Variant::Ep.graph_with_nodes(&auxiliary, &tree_edges)).seeds;
+    let block_of = |v: usize| *block.get(&v).unwrap();
+
+    // a tree edge goes to its own block, a non-tree edge to the block of the tree edge above its deeper endpoint
+    let mut labelled: Vec<(usize, (V, V))> = (0..n)
+        .into_par_iter()
+        .flat_map_iter(|v| {
+            let own = (v != root).then(|| (block_of(v), edge(parent[v], v)));
+            let others = adjacency[v]
+                .iter()
+                .filter(move |&&w| v < w && !is_tree(v, w))
+                .map(move |&w| if pre[v] > pre[w] { (block_of(v), edge(v, w)) } else { (block_of(w), edge(v, w)) });
+            own.into_iter().chain(others)
+        })
+        .collect();
+    labelled.par_sort_unstable();
+
+    let mut blocks: Vec<Vec<(V, V)>> = labelled.chunk_by(|a, b| a.0 == b.0).map(|chunk| chunk.iter().map(|&(_, e)| e).collect()).collect();
+    blocks.par_sort_unstable();
+
+    // articulation points touch the tree edges of two blocks
+    let mut articulation_points: Vec<V> = (0..n)
+        .into_par_iter()
+        .filter(|&v| {
+            let mut labels = children[v].iter().map(|&c| block_of(c)).chain((v != root).then(|| block_of(v)));
+            labels.next().is_some_and(|first| labels.any(|label| label != first))
+        })
+        .map(|v| members[v])
+        .collect();
+    articulation_points.par_sort_unstable();
+
+    let bridges: Vec<(V, V)> = blocks.iter().filter(|block| block.len() == 1).map(|block| block[0]).collect();
+
+    Biconnectivity { seed, nodes: n, blocks, articulation_points, bridges }
+}
+
+/// Content of the `--biconnected` file
+#[derive(Clone, Debug, Serialize)]
+pub struct BiconnectivityReport<'a, V> {
+    pub file: &'a str,
+    pub variant: &'a str,
+    pub components: &'a [Biconnectivity<V>],
+}
+
+/// Write the report to `filename` (`-` for stdout): JSON for `.json` files, a block per component otherwise
+pub fn write_biconnectivity<V>(filename: &str, report: &BiconnectivityReport<V>) -> Result<(), Error>
+where
+    V: NodeTrait + Serialize + fmt::Display,
+{
+    let mut out: Box<dyn Write> = match filename {
+        "-" => Box::new(io::stdout()),
+        _ => Box::new(BufWriter::new(File::create(filename)?)),
+    };
+
+    if Path::new(filename).extension().is_some_and(|ext| ext == "json") {
+        return serde_json::to_writer_pretty(out, report).map_err(Error::from);
+    }
+
+    writeln!(out, "file: {}", report.file)?;
+    writeln!(out, "variant: {}", report.variant)?;
+    for c in report.components {
+        writeln!(
+            out,
+            "component {}: {} nodes, {} biconnected components, {} articulation points, {} bridges",
+            c.seed,
+            c.nodes,
+            c.blocks.len(),
+            c.articulation_points.len(),
+            c.bridges.len()
+        )?;
+        if !c.articulation_points.is_empty() {
+            let points: Vec<String> = c.articulation_points.iter().map(|v| v.to_string()).collect();
+            writeln!(out, "  articulation points: {}", points.join(" "))?;
+        }
+        if !c.bridges.is_empty() {
+            let bridges: Vec<String> = c.bridges.iter().map(|(a, b)| format!("{a}-{b}")).collect();
+            writeln!(out, "  bridges: {}", bridges.join(" "))?;
+        }
+    }
+
+    out.flush()
+}
@@ -0,0 +1,143 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Error, Write},
+    path::Path,
+};
+
+use concurrent_graph::{GraphTrait, NodeTrait};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::bfs::{bfs, dense, Bfs};
+use crate::components::Components;
+use crate::cracker::InputGraph;
+
+/// Outcome of two-coloring a connected component
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "coloring", rename_all = "snake_case")]
+pub enum Coloring<V> {
+    /// Nodes on the seed's side and on the other one
+    Bipartite { sides: [usize; 2] },
+    /// Nodes of an odd cycle in order, the last one adjacent to the first; a self-loop is a cycle of one node
+    OddCycle { cycle: Vec<V> },
+}
+
+/// Two-coloring of a connected component
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Bipartiteness<V> {
+    pub seed: V,
+    pub nodes: usize,
+    #[serde(flatten)]
+    pub coloring: Coloring<V>,
+}
+
+impl<V> Bipartiteness<V> {
+    pub fn is_bipartite(&self) -> bool {
+        matches!(self.coloring, Coloring::Bipartite { .. })
+    }
+}
+
+/// Two-color every connected component, in component order: components run in parallel, and each one is colored by the
+/// parity of its levels in a parallel BFS from the seed, then its edges are checked in parallel for a same-level conflict
+pub fn bipartiteness<V: NodeTrait>(graph: &InputGraph<V>, components: &Components<V>) -> Vec<Bipartiteness<V>> {
+    match graph {
+        InputGraph::Undirected(g) => color(g, components),
+        InputGraph::Directed(g) => color(g, components),
+    }
+}
+
+fn color<G, V>(graph: &G, components: &Components<V>) -> Vec<Bipartiteness<V>>
+where
+    G: GraphTrait<V> + Sync,
+    V: NodeTrait,
+{
+    (0..components.len())
+        .into_par_iter()
+        .map(|c| {
+            let (seed, members) = (components.seed(c), components.members(c));
+            Bipartiteness { seed, nodes: members.len(), coloring: two_color(graph, seed, members) }
+        })
+        .collect()
+}
+
+fn two_color<G, V>(graph: &G, seed: V, members: &[V]) -> Coloring<V>
+where
+    G: GraphTrait<V> + Sync,
+    V: NodeTrait,
+{
+    let (index, adjacency) = dense(graph, members);
+    let Bfs { parent, level, levels } = bfs(&adjacency, index[&seed]);
+
+    // edges of a BFS join consecutive levels or the same one, the latter close an odd cycle; the smallest one is reported
+    let conflict = (0..members.len())
+        .into_par_iter()
+        .filter_map(|u| adjacency[u].iter().find(|&&w| u <= w && level[u] == level[w]).map(|&w| (u, w)))
+        .min();
+
+    match conflict {
+        Some((u, w)) => Coloring::OddCycle { cycle: odd_cycle(&parent, u, w).into_iter().map(|v| members[v]).collect() },
+        None => {
+            let even: usize = levels.par_iter().step_by(2).map(Vec::len).sum();
+            Coloring::Bipartite { sides: [even, members.len() - even] }
+        }
+    }
+}
+
+/// u ... lca ... w through the BFS parents of u and w, which sit on the same level
+fn odd_cycle(parent: &[usize], u: usize, w: usize) -> Vec<usize> {
+    let (mut a, mut b) = (u, w);
+    let mut up = vec![a];
+    let mut down = Vec::new();
+
+    while a != b {
+        down.push(b);
+        a = parent[a];
+        b = parent[b];
+        up.push(a);
+    }
+
+    up.extend(down.into_iter().rev());
+    up
+}
+
+/// Content of the `--bipartite` file
+#[derive(Clone, Debug, Serialize)]
+pub struct BipartitenessReport<'a, V> {
+    pub file: &'a str,
+    pub variant: &'a str,
+    pub bipartite: usize,
+    pub components: &'a [Bipartiteness<V>],
+}
+
+/// Write the report to `filename` (`-` for stdout): JSON for `.json` files, a line per component otherwise
+pub fn write_bipartiteness<V>(filename: &str, report: &BipartitenessReport<V>) -> Result<(), Error>
+where
+    V: NodeTrait + Serialize + fmt::Display,
+{
+    let mut out: Box<dyn Write> = match filename {
+        "-" => Box::new(io::stdout()),
+        _ => Box::new(BufWriter::new(File::create(filename)?)),
+    };
+
+    if Path::new(filename).extension().is_some_and(|ext| ext == "json") {
+        return serde_json::to_writer_pretty(out, report).map_err(Error::from);
+    }
+
+    writeln!(out, "file: {}", report.file)?;
+    writeln!(out, "variant: {}", report.variant)?;
+    writeln!(out, "bipartite components: {} of {}", report.bipartite, report.components.len())?;
+    for c in report.components {
+        match &c.coloring {
+            Coloring::Bipartite { sides } => {
+                writeln!(out, "component {}: {} nodes, bipartite {} + {}", c.seed, c.nodes, sides[0], sides[1])?
+            }
+            Coloring::OddCycle { cycle } => {
+                let cycle: Vec<String> = cycle.iter().map(|v| v.to_string()).collect();
+                writeln!(out, "component {}: {} nodes, odd cycle {}", c.seed, c.nodes, cycle.join(" "))?
+            }
+        }
+    }
+
+    out.flush()
+}
@@ -0,0 +1,149 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::Display,
+    io::{Error, Write},
+};
+
+use concurrent_graph::{ConcurrentWeightedUnGraph, NodeTrait};
+use io_util::prelude::GraphFormat;
+use rayon::prelude::*;
+
+use crate::union_find::UnionFind;
+use crate::weighted::WeightedEdge;
+
+/// Tree of the forest spanning a connected component
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentTree<V> {
+    /// Smallest node of the component
+    pub seed: V,
+    pub nodes: usize,
+    pub edges: usize,
+    pub weight: f64,
+}
+
+/// Minimum spanning forest: (a, b, weight) edges with a < b sorted, and a tree per component sorted by seed
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanningForest<V> {
+    pub edges: Vec<WeightedEdge<V>>,
+    pub trees: Vec<ComponentTree<V>>,
+}
+
+impl<V> SpanningForest<V> {
+    pub fn weight(&self) -> f64 {
+        self.trees.iter().map(|t| t.weight).sum()
+    }
+}
+
+/// Edges ordered by weight, then by endpoints, so that every cut has a single cheapest edge and no round closes a cycle
+fn cheaper<V: Ord>(a: &(f64, V, V), b: &(f64, V, V)) -> Ordering {
+    a.0.total_cmp(&b.0).then_with(|| (&a.1, &a.2).cmp(&(&b.1, &b.2)))
+}
+
+/// Borůvka: every round each component picks, in parallel over its nodes, its cheapest edge leaving it — the step
+/// CRACKER's min selection takes with node ids instead of weights — and the picked edges merge the components
+pub fn boruvka<V: NodeTrait>(graph: &ConcurrentWeightedUnGraph<V, f64>) -> SpanningForest<V> {
+    let mut nodes = graph.nodes();
+    nodes.par_sort_unstable();
+    let index: HashMap<V, usize> = nodes.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+    // (weight, neighbor) per node, self-loops never span anything
+    let neighborhoods = graph.get_neighborhoods();
+    let adjacency: Vec<Vec<(f64, usize)>> = nodes
+        .par_iter()
+        .map(|v| {
+            neighborhoods.get(v).map_or(Vec::new(), |n| n.iter().filter(|(u, _)| *u != v).map(|(u, &w)| (w, index[u])).collect())
+        })
+        .collect();
+
+    let mut sets = UnionFind::new(nodes.len());
+    let mut component: Vec<usize> = (0..nodes.len()).collect();
+    let mut forest: Vec<(f64, usize, usize)> = Vec::new();
+
+    loop {
+        // cheapest edge leaving the component of every node, then of every component
+        let cheapest: HashMap<usize, (f64, usize, usize)> = (0..nodes.len())
+            .into_par_iter()
+            .filter_map(|v| {
+                adjacency[v]
+                    .iter()
+                    .filter(|&&(_, u)| component[u] != component[v])
+                    .map(|&(w, u)| (component[v], (w, v.min(u), v.max(u))))
+                    .min_by(|a, b| cheaper(&a.1, &b.1))
+            })
+            .fold(HashMap::new, |mut best: HashMap<usize, (f64, usize, usize)>, (c, edge)| {
+                let current = best.entry(c).or_insert(edge);
+                if cheaper(&edge, current) == Ordering::Less {
+                    *current = edge;
+                }
+                best
+            })
+            .reduce(HashMap::new, |mut a, b| {
+                for (c, edge) in b {
+                    let current = a.entry(c).or_insert(edge);
+                    if cheaper(&edge, current) == Ordering::Less {
+                        *current = edge;
+                    }
+                }
+                a
+            });
+
+        if cheapest.is_empty() {
+            break;
+        }
+
+        // two components may pick the same edge
+        let mut picked: Vec<(f64, usize, usize)> = cheapest.into_values().collect();
+        picked.sort_unstable_by(cheaper);
+        picked.dedup_by(|a, b| (a.1, a.2) == (b.1, b.2));
+
+        for edge in picked {
+            if sets.union(edge.1, edge.2) {
+                forest.push(edge);
+            }
+        }
+        component = (0..nodes.len()).map(|v| sets.find(v)).collect();
+    }
+
+    let mut trees: HashMap<usize, ComponentTree<V>> = HashMap::new();
+    for (v, &c) in component.iter().enumerate() {
+        let tree = trees.entry(c).or_insert(ComponentTree { seed: nodes[v], nodes: 0, edges: 0, weight: 0.0 });
+        tree.nodes += 1;
+    }
+    for &(w, a, _) in &forest {
+        let tree = trees.get_mut(&component[a]).unwrap();
+        tree.edges += 1;
+        tree.weight += w;
+    }
+
+    let mut edges: Vec<WeightedEdge<V>> = forest.into_iter().map(|(w, a, b)| (nodes[a], nodes[b], w)).collect();
+    edges.par_sort_unstable_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    let mut trees: Vec<ComponentTree<V>> = trees.into_values().collect();
+    trees.sort_unstable_by_key(|t| t.seed);
+
+    SpanningForest { edges, trees }
+}
+
+/// Write the forest edges as `a b weight` lines, under a `real` MatrixMarket header for [`GraphFormat::MatrixMarket`]
+pub fn write_forest<W: Write, V: Display>(mut out: W, edges: &[WeightedEdge<V>], num_nodes: usize, format: GraphFormat) -> Result<(), Error> {
+    if format == GraphFormat::MatrixMarket {
+        writeln!(out, "%%MatrixMarket matrix coordinate real symmetric")?;
+        writeln!(out, "{} {} {}", num_nodes, num_nodes, edges.len())?;
+    }
+
+    for (a, b, w) in edges {
+        writeln!(out, "{} {} {}", a, b, w)?;
+    }
+
+    out.flush()
+}
+
+/// Write a `seed,nodes,edges,weight` csv row per tree
+pub fn write_trees<W: Write, V: Display>(mut out: W, trees: &[ComponentTree<V>]) -> Result<(), Error> {
+    writeln!(out, "seed,nodes,edges,weight")?;
+    for t in trees {
+        writeln!(out, "{},{},{},{}", t.seed, t.nodes, t.edges, t.weight)?;
+    }
+
+    out.flush()
+}
@@ -1,10 +1,9 @@
 #![allow(unused_imports)]
 use concurrent_graph::{ConcurrentDiGraph, ConcurrentUnGraph, GraphTrait};
-use concurrentgraph_utils_rayon::{min_selection_base, par_seed_propagation, prune};
+use graph_example::concurrentgraph_utils_rayon::{min_selection_base, par_seed_propagation, prune};
 use dashmap::DashSet;
 use rayon::{iter, ThreadPoolBuilder};
 
-mod concurrentgraph_utils_rayon;
 fn main() {
     let num_threads = 4;
     ThreadPoolBuilder::new()
@@ -0,0 +1,280 @@
+use getopts::Options;
+use io_util::prelude::{read_from_file, read_num_nodes, read_weighted_from_file, write_to_file, GraphFormat};
+use rayon::ThreadPoolBuilder;
+use tracing::info;
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter},
+    time::Instant,
+};
+
+use crate::biconnected::{biconnectivity, write_biconnectivity, BiconnectivityReport};
+use crate::bipartite::{bipartiteness, write_bipartiteness, BipartitenessReport};
+use crate::components::{write_components, write_labels, ComponentReport, ComponentStats, Labeling};
+use crate::cracker::Variant;
+use crate::forest::Witnesses;
+use crate::invariants::InvariantChecker;
+use crate::logging;
+use crate::memory::{is_counting, peak_rss_bytes, write_memory, MemoryProfile, MemoryReport};
+use crate::stats::{write_stats, StatsCollector, StatsReport};
+use crate::trace::TraceWriter;
+use crate::tree::{parent_array, write_dot, write_parents, write_tree_stats, TreeReport, TreeStats};
+use crate::weighted::{min_weight, nodes};
+
+/// Shared main of the base, ep and epos binaries
+pub fn main(variant: Variant) {
+    env::set_var("RUST_BACKTRACE", "1");
+
+    type V = u32;
+
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("f", "file", "provide the file containg the graph output file name", "FILEPATH");
+    opts.optopt("n", "num_thread", "provide the number of threads to use", "0");
+    opts.optopt("", "min_weight", "only keep the edges with value >= TAU of a real/integer MatrixMarket file", "TAU");
+    opts.optopt("", "stats", "write per-iteration statistics to a json file", "FILEPATH");
+    opts.optopt("", "memory", "write per-phase memory usage to a json file (heap counters need the `memory` feature)", "FILEPATH");
+    opts.optopt("", "components", "write component statistics, json for .json files, text otherwise (- = stdout)", "FILEPATH");
+    opts.optopt("", "labels", "write a `node component` line per node, sorted by node (- = stdout)", "FILEPATH");
+    opts.optopt("", "canonical", "component label written by --labels (default seed)", "seed|min|dense-min|dense-size");
+    opts.optopt("", "forest", "track the input edges behind every edge of T during the run and write a spanning forest built from them, mtx for .mtx files, edge list otherwise", "FILEPATH");
+    opts.optopt("", "biconnected", "write biconnected components, articulation points and bridges per component, json for .json files, text otherwise (- = stdout)", "FILEPATH");
+    opts.optopt("", "bipartite", "write which components are bipartite, with an odd cycle for the others, json for .json files, text otherwise (- = stdout)", "FILEPATH");
+    opts.optopt("", "tree", "write the propagation tree T as a `node parent` line per node, roots are their own parent", "FILEPATH");
+    opts.optopt("", "tree_dot", "write the propagation tree T as Graphviz DOT", "FILEPATH");
+    opts.optopt("", "tree_stats", "write depth and fan-out statistics of T, json for .json files, text otherwise (- = stdout)", "FILEPATH");
+    opts.optopt("", "trace_dir", "write g_t, h_t and the cumulative T of every iteration as edge lists, plus summary.csv", "DIR");
+    opts.optflag("", "check", "verify T and the connectivity of G_t ∪ T after every iteration (on by default in debug builds)");
+    opts.optflag("", "no_check", "skip the checks of debug builds (they are slow and skew the timings and --memory)");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} FILE [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    //handle -n/--num_threads
+    let num_threads = match matches.opt_str("n") {
+        None => 0,  //let rayon decide
+        Some(v) => v.parse().unwrap(),
+    };
+
+    //setup parallelism
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()
+        .unwrap();
+
+
+    //handle -f/--filename
+    let filename = matches.opt_str("f");
+    if filename.is_none() {
+        println!("Please provide a filename");
+        return;
+    }
+
+    let tau: Option<f64> = match matches.opt_str("min_weight").map(|t| t.parse()) {
+        None => None,
+        Some(Ok(tau)) => Some(tau),
+        Some(Err(_)) => {
+            eprintln!("Invalid min_weight");
+            return;
+        }
+    };
+
+    let labeling: Labeling = match matches.opt_str("canonical").map(|l| l.parse()) {
+        None => Labeling::default(),
+        Some(Ok(labeling)) => labeling,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let mut memory = matches.opt_str("memory").map(|_| MemoryProfile::start());
+
+    let filename = filename.unwrap();
+    let load = Instant::now();
+    // under --min_weight every node is kept, even when all its edges are dropped
+    let edges_result = match tau {
+        None => read_from_file::<V>(filename.as_str()).map(|edges| (edges, vec![])),
+        Some(tau) => read_weighted_from_file::<V, f64>(filename.as_str()).map(|weighted| (min_weight(&weighted, tau), nodes(&weighted))),
+    };
+    if edges_result.is_err() {
+        println!("Error reading edges from file: {:?}", edges_result.err());
+        return;
+    }
+
+    let (edges, nodes): (Vec<(V, V)>, Vec<V>) = edges_result.unwrap_or_default();
+    let graph = variant.graph_with_nodes(&edges, &nodes);
+    info!(
+        file = %filename,
+        variant = variant.name(),
+        nodes = graph.node_count(),
+        edges = edges.len(),
+        min_weight = ?tau,
+        num_threads = rayon::current_num_threads(),
+        elapsed = ?load.elapsed(),
+        "loaded"
+    );
+    if let Some(memory) = memory.as_mut() {
+        memory.phase("load");
+    }
+
+    let trace = match matches.opt_str("trace_dir").map(TraceWriter::new) {
+        None => None,
+        Some(Ok(trace)) => Some(trace),
+        Some(Err(e)) => {
+            eprintln!("Error creating the trace directory: {e}");
+            return;
+        }
+    };
+
+    let check = matches.opt_present("check") || (cfg!(debug_assertions) && !matches.opt_present("no_check"));
+    let checker = check.then(|| InvariantChecker::with_nodes(&edges, &nodes));
+
+    let stats = matches.opt_str("stats").map(|_| StatsCollector::default());
+    // hooks run in order: the slow checker goes last, after the cheap observers
+    let mut observers = ((stats, memory), (trace, checker));
+
+    // --forest makes min selection and pruning record where every edge comes from
+    let witnesses = matches.opt_present("forest").then(Witnesses::new);
+    let res = match &witnesses {
+        None => variant.crack_observed(&graph, &mut observers),
+        Some(witnesses) => variant.crack_witnessed(&graph, &mut observers, witnesses),
+    };
+    println!("{:?}", res.main_loop.as_millis());
+
+    let ((stats, memory), (trace, _)) = observers;
+
+    if let Some(Err(e)) = trace.map(TraceWriter::finish) {
+        eprintln!("Error writing the trace: {e}");
+    }
+
+    if let (Some(stats_file), Some(stats)) = (matches.opt_str("stats"), stats) {
+        let report = StatsReport {
+            file: &filename,
+            variant: variant.name(),
+            num_threads: rayon::current_num_threads(),
+            iterations: &stats.iterations,
+        };
+
+        if let Err(e) = write_stats(&stats_file, &report) {
+            eprintln!("Error writing stats: {e}");
+        }
+    }
+
+    if let (Some(memory_file), Some(memory)) = (matches.opt_str("memory"), memory) {
+        let report = MemoryReport {
+            file: &filename,
+            variant: variant.name(),
+            counting: is_counting(),
+            peak_bytes: memory.peak_bytes(),
+            allocations: memory.allocations(),
+            peak_rss_bytes: peak_rss_bytes(),
+            phases: &memory.phases,
+        };
+
+        if let Err(e) = write_memory(&memory_file, &report) {
+            eprintln!("Error writing memory usage: {e}");
+        }
+    }
+
+    if let Some(components_file) = matches.opt_str("components") {
+        // nodes of the header missing from the edge list are components of their own
+        let isolated = match read_num_nodes(&filename) {
+            Ok(num_nodes) => num_nodes.unwrap_or(0).saturating_sub(graph.node_count()),
+            Err(e) => {
+                eprintln!("Error reading the number of nodes: {e}");
+                0
+            }
+        };
+        let stats = ComponentStats::from_cracker(&res).with_isolated(isolated);
+        let report = ComponentReport { file: &filename, variant: variant.name(), stats: &stats };
+
+        if let Err(e) = write_components(&components_file, &report) {
+            eprintln!("Error writing component statistics: {e}");
+        }
+    }
+
+    if let Some(labels_file) = matches.opt_str("labels") {
+        let written = match labels_file.as_str() {
+            "-" => write_labels(io::stdout().lock(), res.components(), labeling),
+            _ => File::create(&labels_file).and_then(|f| write_labels(BufWriter::new(f), res.components(), labeling)),
+        };
+
+        if let Err(e) = written {
+            eprintln!("Error writing labels: {e}");
+        }
+    }
+
+    if let (Some(forest_file), Some(witnesses)) = (matches.opt_str("forest"), witnesses) {
+        let forest = witnesses.spanning_forest();
+
+        if let Err(e) = write_to_file(&forest_file, &forest, graph.node_count(), GraphFormat::from_path(&forest_file)) {
+            eprintln!("Error writing the spanning forest: {e}");
+        }
+    }
+
+    if let Some(biconnected_file) = matches.opt_str("biconnected") {
+        let components = biconnectivity(&graph, &res.components());
+        let report = BiconnectivityReport { file: &filename, variant: variant.name(), components: &components };
+
+        if let Err(e) = write_biconnectivity(&biconnected_file, &report) {
+            eprintln!("Error writing biconnected components: {e}");
+        }
+    }
+
+    if let Some(bipartite_file) = matches.opt_str("bipartite") {
+        let components = bipartiteness(&graph, &res.components());
+        let bipartite = components.iter().filter(|c| c.is_bipartite()).count();
+        let report = BipartitenessReport { file: &filename, variant: variant.name(), bipartite, components: &components };
+
+        if let Err(e) = write_bipartiteness(&bipartite_file, &report) {
+            eprintln!("Error writing bipartiteness: {e}");
+        }
+    }
+
+    let parents = (matches.opt_present("tree") || matches.opt_present("tree_dot")).then(|| parent_array(&res.tree));
+
+    if let (Some(tree_file), Some(parents)) = (matches.opt_str("tree"), &parents) {
+        if let Err(e) = File::create(&tree_file).and_then(|f| write_parents(BufWriter::new(f), parents)) {
+            eprintln!("Error writing the tree: {e}");
+        }
+    }
+
+    if let (Some(dot_file), Some(parents)) = (matches.opt_str("tree_dot"), &parents) {
+        if let Err(e) = File::create(&dot_file).and_then(|f| write_dot(BufWriter::new(f), parents)) {
+            eprintln!("Error writing the tree: {e}");
+        }
+    }
+
+    if let Some(stats_file) = matches.opt_str("tree_stats") {
+        let stats = TreeStats::new(&res);
+        let report = TreeReport { file: &filename, variant: variant.name(), iterations: res.iterations, stats: &stats };
+
+        if let Err(e) = write_tree_stats(&stats_file, &report) {
+            eprintln!("Error writing tree statistics: {e}");
+        }
+    }
+
+    info!(iterations = res.iterations, components = res.num_components(), "done");
+}
@@ -0,0 +1,174 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader, Error, ErrorKind},
+};
+
+use serde::Serialize;
+
+/// {node: component label}
+pub type Labels = HashMap<u64, u64>;
+
+/// Read a `node label` file as written by `--labels`; empty lines and `%`/`#` comments are skipped, a node listed twice is an error
+pub fn read_labels(filename: &str) -> Result<Labels, Error> {
+    let mut labels = Labels::new();
+
+    for (i, line) in BufReader::new(File::open(filename)?).lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace().map(str::parse::<u64>);
+        match (fields.next(), fields.next()) {
+            (Some(Ok(v)), Some(Ok(label))) => {
+                if labels.insert(v, label).is_some() {
+                    let msg = format!("{filename}:{}: node {v} is labelled more than once", i + 1);
+                    return Err(Error::new(ErrorKind::InvalidData, msg));
+                }
+            }
+            _ => {
+                let msg = format!("{filename}:{}: expected `node label`, found {line:?}", i + 1);
+                return Err(Error::new(ErrorKind::InvalidData, msg));
+            }
+        }
+    }
+
+    Ok(labels)
+}
+
+/// A component of one labeling spread over several components of the other
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Split {
+    pub label: u64,
+    pub size: usize,
+    /// (label in the other labeling, #shared nodes), largest first
+    pub parts: Vec<(u64, usize)>,
+}
+
+/// Outcome of [`compare`], computed over the nodes of both labelings
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Comparison {
+    pub nodes: usize,
+    pub only_left: usize,
+    pub only_right: usize,
+    pub left_components: usize,
+    pub right_components: usize,
+    /// Same partition up to relabeling (and same nodes)
+    pub identical: bool,
+    /// Left components split among several right ones
+    pub splits: Vec<Split>,
+    /// Right components merging several left ones
+    pub merges: Vec<Split>,
+    pub rand_index: f64,
+    pub adjusted_rand_index: f64,
+    /// In nats, 0 for identical partitions
+    pub variation_of_information: f64,
+}
+
+fn pairs(n: usize) -> u128 {
+    let n = n as u128;
+    n * n.saturating_sub(1) / 2
+}
+
+/// Components of `sizes` whose nodes are spread over several labels of the other side
+fn splits(sizes: &HashMap<u64, usize>, table: &HashMap<(u64, u64), usize>, left: bool) -> Vec<Split> {
+    let mut parts: HashMap<u64, Vec<(u64, usize)>> = HashMap::new();
+    for (&(l, r), &n) in table {
+        let (label, other) = if left { (l, r) } else { (r, l) };
+        parts.entry(label).or_default().push((other, n));
+    }
+
+    let mut splits: Vec<Split> = parts
+        .into_iter()
+        .filter(|(_, parts)| parts.len() > 1)
+        .map(|(label, mut parts)| {
+            parts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            Split { label, size: sizes[&label], parts }
+        })
+        .collect();
+    splits.sort_unstable_by(|a, b| b.size.cmp(&a.size).then(a.label.cmp(&b.label)));
+
+    splits
+}
+
+/// Compare two labelings of (mostly) the same nodes
+pub fn compare(left: &Labels, right: &Labels) -> Comparison {
+    // contingency table over the shared nodes
+    let mut table: HashMap<(u64, u64), usize> = HashMap::new();
+    for (v, l) in left {
+        if let Some(r) = right.get(v) {
+            *table.entry((*l, *r)).or_insert(0) += 1;
+        }
+    }
+
+    let mut left_sizes: HashMap<u64, usize> = HashMap::new();
+    let mut right_sizes: HashMap<u64, usize> = HashMap::new();
+    for (&(l, r), &n) in &table {
+        *left_sizes.entry(l).or_insert(0) += n;
+        *right_sizes.entry(r).or_insert(0) += n;
+    }
+
+    let nodes: usize = table.values().sum();
+    let total = pairs(nodes);
+    let both: u128 = table.values().map(|&n| pairs(n)).sum();
+    let in_left: u128 = left_sizes.values().map(|&n| pairs(n)).sum();
+    let in_right: u128 = right_sizes.values().map(|&n| pairs(n)).sum();
+
+    // pairs together in both + pairs apart in both
+    let rand_index = if total > 0 { (total + 2 * both - in_left - in_right) as f64 / total as f64 } else { 1.0 };
+
+    let expected = in_left as f64 * in_right as f64 / total.max(1) as f64;
+    let max = (in_left + in_right) as f64 / 2.0;
+    let adjusted_rand_index = if max - expected != 0.0 { (both as f64 - expected) / (max - expected) } else { 1.0 };
+
+    let n = nodes as f64;
+    let variation_of_information = table
+        .iter()
+        .map(|(&(l, r), &nij)| {
+            let nij = nij as f64;
+            nij / n * ((left_sizes[&l] as f64 / nij).ln() + (right_sizes[&r] as f64 / nij).ln())
+        })
+        .sum();
+
+    let only_left = left.len() - nodes;
+    let only_right = right.len() - nodes;
+
+    Comparison {
+        nodes,
+        only_left,
+        only_right,
+        left_components: left_sizes.len(),
+        right_components: right_sizes.len(),
+        identical: only_left == 0 && only_right == 0 && table.len() == left_sizes.len() && table.len() == right_sizes.len(),
+        splits: splits(&left_sizes, &table, true),
+        merges: splits(&right_sizes, &table, false),
+        rand_index,
+        adjusted_rand_index,
+        variation_of_information,
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.identical { "identical up to relabeling" } else { "different" };
+        writeln!(f, "partitions: {verdict}")?;
+        writeln!(f, "nodes: {} (only left: {}, only right: {})", self.nodes, self.only_left, self.only_right)?;
+        writeln!(f, "components: {} left, {} right", self.left_components, self.right_components)?;
+        writeln!(f, "rand index: {:.6}", self.rand_index)?;
+        writeln!(f, "adjusted rand index: {:.6}", self.adjusted_rand_index)?;
+        writeln!(f, "variation of information: {:.6}", self.variation_of_information)?;
+
+        for (title, splits) in [("split", &self.splits), ("merged", &self.merges)] {
+            writeln!(f, "{title} components: {}", splits.len())?;
+            for s in splits {
+                let parts: Vec<String> = s.parts.iter().map(|(label, n)| format!("{label} ({n})")).collect();
+                writeln!(f, "  {} ({}) -> {}", s.label, s.size, parts.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Error, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use concurrent_graph::NodeTrait;
+use dashmap::DashMap;
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::cracker::Cracker;
+
+/// Connected components with dense ids `0..len()`, ordered by seed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components<V> {
+    /// Members of component c are `members[offsets[c]..offsets[c + 1]]`, sorted
+    members: Vec<V>,
+    offsets: Vec<usize>,
+    seeds: Vec<V>,
+    /// Every node sorted, with the component of `nodes[i]` in `labels[i]`
+    nodes: Vec<V>,
+    labels: Vec<usize>,
+}
+
+impl<V: NodeTrait> Components<V> {
+    /// Build from a {node: seed} map
+    pub fn new(seeds: &DashMap<V, V>) -> Self {
+        let mut pairs: Vec<(V, V)> = seeds.iter().map(|entry| (*entry.value(), *entry.key())).collect();
+        pairs.par_sort_unstable();
+
+        let mut members = Vec::with_capacity(pairs.len());
+        let mut offsets = vec![0];
+        let mut component_seeds: Vec<V> = Vec::new();
+        for (seed, v) in pairs {
+            if component_seeds.last() != Some(&seed) {
+                if !members.is_empty() {
+                    offsets.push(members.len());
+                }
+                component_seeds.push(seed);
+            }
+            members.push(v);
+        }
+        if !members.is_empty() {
+            offsets.push(members.len());
+        }
+
+        let mut labelled: Vec<(V, usize)> = offsets
+            .windows(2)
+            .enumerate()
+            .flat_map(|(c, w)| members[w[0]..w[1]].iter().map(move |&v| (v, c)))
+            .collect();
+        labelled.par_sort_unstable();
+        let (nodes, labels) = labelled.into_iter().unzip();
+
+        Components { members, offsets, seeds: component_seeds, nodes, labels }
+    }
+
+    /// Number of components
+    pub fn len(&self) -> usize {
+        self.seeds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seeds.is_empty()
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Id of the component of `v`, None if `v` is not in the graph
+    pub fn component_of(&self, v: V) -> Option<usize> {
+        self.index_of(v).map(|i| self.labels[i])
+    }
+
+    /// Dense id of `v` in `0..num_nodes()` (its rank among the nodes)
+    pub fn index_of(&self, v: V) -> Option<usize> {
+        self.nodes.binary_search(&v).ok()
+    }
+
+    /// false if either node is not in the graph
+    pub fn same_component(&self, u: V, v: V) -> bool {
+        match (self.component_of(u), self.component_of(v)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Sorted nodes of component `c`
+    pub fn members(&self, c: usize) -> &[V] {
+        &self.members[self.offsets[c]..self.offsets[c + 1]]
+    }
+
+    /// Seed CRACKER elected for component `c`
+    pub fn seed(&self, c: usize) -> V {
+        self.seeds[c]
+    }
+
+    /// Size of every component, by id
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.offsets.windows(2).map(|w| w[1] - w[0])
+    }
+
+    /// Members of every component, by id
+    pub fn iter(&self) -> impl Iterator<Item = &[V]> + '_ {
+        self.offsets.windows(2).map(|w| &self.members[w[0]..w[1]])
+    }
+
+    /// (node, component id) of every node, by node
+    pub fn labelled(&self) -> impl Iterator<Item = (V, usize)> + '_ {
+        self.nodes.iter().copied().zip(self.labels.iter().copied())
+    }
+
+    /// Renumber the components by minimum node
+    pub fn by_min(self) -> Self {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_unstable_by_key(|&c| self.members(c)[0]);
+        self.permute(&order)
+    }
+
+    /// Renumber the components largest first, ties by minimum node
+    pub fn by_size(self) -> Self {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_unstable_by_key(|&c| (std::cmp::Reverse(self.members(c).len()), self.members(c)[0]));
+        self.permute(&order)
+    }
+
+    /// New component i is the old component `order[i]`
+    fn permute(self, order: &[usize]) -> Self {
+        let mut members = Vec::with_capacity(self.members.len());
+        let mut offsets = Vec::with_capacity(self.offsets.len());
+        let mut new_id = vec![0; order.len()];
+
+        offsets.push(0);
+        for (i, &c) in order.iter().enumerate() {
+            members.extend_from_slice(self.members(c));
+            offsets.push(members.len());
+            new_id[c] = i;
+        }
+
+        Components {
+            members,
+            offsets,
+            seeds: order.iter().map(|&c| self.seeds[c]).collect(),
+            labels: self.labels.iter().map(|&c| new_id[c]).collect(),
+            nodes: self.nodes,
+        }
+    }
+}
+
+impl<'a, V: NodeTrait> IntoIterator for &'a Components<V> {
+    type Item = &'a [V];
+    type IntoIter = Box<dyn Iterator<Item = &'a [V]> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+/// Label written for each node by [`write_labels`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Labeling {
+    /// Seed elected by CRACKER (depends on T)
+    #[default]
+    Seed,
+    /// Minimum node of the component
+    Min,
+    /// Dense ids 0..k ordered by minimum node
+    DenseMin,
+    /// Dense ids 0..k, largest component first
+    DenseSize,
+}
+
+impl FromStr for Labeling {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "seed" => Ok(Labeling::Seed),
+            "min" => Ok(Labeling::Min),
+            "dense-min" => Ok(Labeling::DenseMin),
+            "dense-size" => Ok(Labeling::DenseSize),
+            _ => Err(format!("unknown labeling {s:?} (expected seed, min, dense-min or dense-size)")),
+        }
+    }
+}
+
+/// One `node label` line per node, sorted by node
+pub fn write_labels<W, V>(mut out: W, components: Components<V>, labeling: Labeling) -> Result<(), Error>
+where
+    W: Write,
+    V: NodeTrait + fmt::Display,
+{
+    let components = match labeling {
+        Labeling::Seed | Labeling::Min => components,
+        Labeling::DenseMin => components.by_min(),
+        Labeling::DenseSize => components.by_size(),
+    };
+
+    for (v, c) in components.labelled() {
+        match labeling {
+            Labeling::Seed => writeln!(out, "{} {}", v, components.seed(c))?,
+            Labeling::Min => writeln!(out, "{} {}", v, components.members(c)[0])?,
+            Labeling::DenseMin | Labeling::DenseSize => writeln!(out, "{} {}", v, c)?,
+        }
+    }
+
+    out.flush()
+}
+
+/// Components whose size is in `[min, max]`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub min: usize,
+    pub max: usize,
+    pub count: usize,
+}
+
+/// Size distribution of the connected components
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ComponentStats {
+    pub nodes: usize,
+    pub components: usize,
+    pub largest: usize,
+    pub smallest: usize,
+    /// Components of one node, including the isolated nodes added by [`ComponentStats::with_isolated`]
+    pub singletons: usize,
+    /// largest / nodes
+    pub giant_fraction: f64,
+    /// Power-of-two buckets `[1, 1], [2, 3], [4, 7], ...` up to the largest component
+    pub histogram: Vec<Bucket>,
+}
+
+impl ComponentStats {
+    pub fn new(sizes: &[usize]) -> Self {
+        let nodes: usize = sizes.iter().sum();
+        let largest = sizes.iter().copied().max().unwrap_or(0);
+
+        let mut histogram: Vec<Bucket> = (0..bucket(largest) + usize::from(largest > 0))
+            .map(|k| Bucket { min: 1 << k, max: (1 << (k + 1)) - 1, count: 0 })
+            .collect();
+        for &size in sizes.iter().filter(|&&size| size > 0) {
+            histogram[bucket(size)].count += 1;
+        }
+
+        ComponentStats {
+            nodes,
+            components: sizes.len(),
+            largest,
+            smallest: sizes.iter().copied().min().unwrap_or(0),
+            singletons: sizes.iter().filter(|&&size| size == 1).count(),
+            giant_fraction: if nodes > 0 { largest as f64 / nodes as f64 } else { 0.0 },
+            histogram,
+        }
+    }
+
+    pub fn from_cracker<V: NodeTrait>(res: &Cracker<V>) -> Self {
+        let sizes: Vec<usize> = res.component_sizes().into_iter().map(|(_, size)| size).collect();
+        Self::new(&sizes)
+    }
+
+    /// Add `isolated` singleton components: nodes declared by the input (e.g. a MatrixMarket header) that appear in no edge
+    pub fn with_isolated(mut self, isolated: usize) -> Self {
+        if isolated == 0 {
+            return self;
+        }
+
+        self.nodes += isolated;
+        self.components += isolated;
+        self.singletons += isolated;
+        self.smallest = 1;
+        self.largest = self.largest.max(1);
+        self.giant_fraction = self.largest as f64 / self.nodes as f64;
+        match self.histogram.first_mut() {
+            Some(singletons) => singletons.count += isolated,
+            None => self.histogram.push(Bucket { min: 1, max: 1, count: isolated }),
+        }
+
+        self
+    }
+}
+
+/// Index of the histogram bucket of a (non-zero) size: floor(log2 size)
+fn bucket(size: usize) -> usize {
+    size.max(1).ilog2() as usize
+}
+
+impl fmt::Display for ComponentStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "nodes: {}", self.nodes)?;
+        writeln!(f, "components: {}", self.components)?;
+        writeln!(f, "largest component: {} ({:.2}% of the nodes)", self.largest, self.giant_fraction * 100.0)?;
+        writeln!(f, "smallest component: {}", self.smallest)?;
+        writeln!(f, "singletons: {}", self.singletons)?;
+        writeln!(f, "size histogram:")?;
+
+        let width = self.histogram.last().map_or(1, |b| b.max.to_string().len());
+        for b in &self.histogram {
+            writeln!(f, "  {:>width$} - {:>width$}: {}", b.min, b.max, b.count)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Content of the `--components` file
+#[derive(Clone, Debug, Serialize)]
+pub struct ComponentReport<'a> {
+    pub file: &'a str,
+    pub variant: &'a str,
+    #[serde(flatten)]
+    pub stats: &'a ComponentStats,
+}
+
+/// Write the report to `filename` (`-` for stdout): JSON for `.json` files, human-readable otherwise
+pub fn write_components(filename: &str, report: &ComponentReport) -> Result<(), Error> {
+    let out: Box<dyn Write> = match filename {
+        "-" => Box::new(io::stdout()),
+        _ => Box::new(BufWriter::new(File::create(filename)?)),
+    };
+
+    if Path::new(filename).extension().is_some_and(|ext| ext == "json") {
+        serde_json::to_writer_pretty(out, report).map_err(Error::from)
+    } else {
+        write_text(out, report)
+    }
+}
+
+fn write_text<W: Write>(mut out: W, report: &ComponentReport) -> Result<(), Error> {
+    writeln!(out, "file: {}", report.file)?;
+    writeln!(out, "variant: {}", report.variant)?;
+    write!(out, "{}", report.stats)
+}
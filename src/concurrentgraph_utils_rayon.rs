@@ -6,6 +6,22 @@ use std::{collections::{HashMap, HashSet}, fmt::Debug};
 //use crate::concurrent_graph::{ConcurrentDiGraph, ConcurrentGraph, ConcurrentUnGraph, NodeTrait};
 use concurrent_graph::{ConcurrentDiGraph, ConcurrentUnGraph, NodeTrait, GraphTrait};
 
+/// Told where every edge derived by min selection and pruning comes from, see [`crate::forest::Witnesses`]
+pub trait Witness<N>: Sync {
+    /// Edge a - b of the graph being built, derived from the edges a - via and via - b of the previous one
+    fn derived(&self, a: N, b: N, via: N);
+    /// Tree edge parent -> child, taken from the H edge child -> parent
+    fn tree_edge(&self, parent: N, child: N);
+}
+
+/// No tracking
+impl<N> Witness<N> for () {
+    #[inline(always)]
+    fn derived(&self, _a: N, _b: N, _via: N) {}
+    #[inline(always)]
+    fn tree_edge(&self, _parent: N, _child: N) {}
+}
+
 /// Get the min neighbor of every node
 fn get_vmins<G, V>(graph: &G) -> DashMap<V, V> 
 where G: GraphTrait<V>, V: NodeTrait + Send + Sync {
@@ -26,6 +42,15 @@ where G: GraphTrait<V>, V: NodeTrait + Send + Sync {
 pub fn min_selection_base<N>(g: &ConcurrentUnGraph<N>) -> ConcurrentDiGraph<N>
 where
     N: NodeTrait + Eq + Send + Sync + Debug,
+{
+    min_selection_base_with(g, &())
+}
+
+/// [`min_selection_base`] reporting the origin of every edge of H to `witness`
+pub fn min_selection_base_with<N, W>(g: &ConcurrentUnGraph<N>, witness: &W) -> ConcurrentDiGraph<N>
+where
+    N: NodeTrait + Eq + Send + Sync + Debug,
+    W: Witness<N>,
 {
     let neighborhoods: DashMap<N, HashSet<N>> = g.get_closed_neighborhoods();
     //println!("[MS]: neighborhoods = {neighborhoods:?}"); //debug
@@ -49,9 +74,11 @@ where
 
         // base
         h.add_edge(*u, v_min);
+        witness.derived(*u, v_min, *u);
         neighbors.iter().for_each(|node| {
             //eprintln!("[h] adding: {:?} -> {:?}", node, v_min);
             h.add_edge(*node, v_min);
+            witness.derived(*node, v_min, *u);
         });
 
     });
@@ -65,6 +92,16 @@ pub fn min_selection_ep<G, N>(g: &G) -> ConcurrentDiGraph<N>
 where
     G: GraphTrait<N>,
     N: NodeTrait + Eq + Send + Sync + Debug,
+{
+    min_selection_ep_with(g, &())
+}
+
+/// [`min_selection_ep`] reporting the origin of every edge of H to `witness`
+pub fn min_selection_ep_with<G, N, W>(g: &G, witness: &W) -> ConcurrentDiGraph<N>
+where
+    G: GraphTrait<N>,
+    N: NodeTrait + Eq + Send + Sync + Debug,
+    W: Witness<N>,
 {
     let neighborhoods: DashMap<N, HashSet<N>> = g.get_all_neighborhoods();
     let v_mins: DashMap<N, N> = get_vmins(g);
@@ -100,12 +137,15 @@ where
                 //when a node u is the local minimum in NN(u), [u = u_min] there are two exclusive cases
                 if z_min == n {
                     h.add_edge(*z, n);
+                    witness.derived(*z, n, *z);
                     //eprintln!("[caso A] adding edge {:?}->{:?}", *z, n);
                 } else {
                     h.add_edge(*z, z_min);
+                    witness.derived(*z, z_min, *z);
                     //eprintln!("[caso B] adding edge {:?}->{:?}", *z, z_min);
 
                     h.add_edge(n, z_min);
+                    witness.derived(n, z_min, *z);
                     //eprintln!("[caso B] adding edge {:?}->{:?}", n, z_min);
                 }
                 //eprintln!("removing {:?}", &z);
@@ -113,10 +153,12 @@ where
         } 
         else {
             h.add_edge(n, n_min); // => get_neighborhood return <neighbors + node>
+            witness.derived(n, n_min, n);
             //eprintln!("[caso C] adding edge {:?}->{:?}", n, n_min);
             neighbors.iter().for_each(|node| {
                 //eprintln!("adding: {:?} -> {:?}", node, v_min);
                 h.add_edge(*node, n_min);
+                witness.derived(*node, n_min, n);
                 //eprintln!("[caso C] adding edge {:?}->{:?}", *node, n_min);
             });
         }
@@ -129,6 +171,15 @@ where
 pub fn prune<N: NodeTrait + Send + Sync + Debug>(
     h: ConcurrentDiGraph<N>,
     tree: ConcurrentDiGraph<N>,
+) -> (ConcurrentUnGraph<N>, ConcurrentDiGraph<N>) {
+    prune_with(h, tree, &())
+}
+
+/// [`prune`] reporting the origin of every edge of G_t+1 and T to `witness`
+pub fn prune_with<N: NodeTrait + Send + Sync + Debug, W: Witness<N>>(
+    h: ConcurrentDiGraph<N>,
+    tree: ConcurrentDiGraph<N>,
+    witness: &W,
 ) -> (ConcurrentUnGraph<N>, ConcurrentDiGraph<N>) {
     //eprintln!("Pruning");
     //get outgoing neighborhoods
@@ -150,6 +201,7 @@ pub fn prune<N: NodeTrait + Send + Sync + Debug>(
             neighbors.iter().for_each(|v| {
                 if *v != v_min {
                     pruned_graph.add_edge(*v, v_min);
+                    witness.derived(*v, v_min, *u);
                     //eprintln!("[g]: adding edge {:?} -> {:?}", *v, v_min);
                 }
             });
@@ -166,6 +218,7 @@ pub fn prune<N: NodeTrait + Send + Sync + Debug>(
 
             let v_min = *v_min_opt.unwrap();
             tree.add_edge(v_min, *u);
+            witness.tree_edge(v_min, *u);
             //eprintln!("Adding to tree: {:?} -> {:?}", v_min, *u);
 
             deactivated_nodes.insert(*u);
@@ -194,6 +247,15 @@ pub fn prune<N: NodeTrait + Send + Sync + Debug>(
 pub fn prune_os<N: NodeTrait + Debug>(
     h: ConcurrentDiGraph<N>,
     tree: ConcurrentDiGraph<N>,
+) -> (ConcurrentDiGraph<N>, ConcurrentDiGraph<N>) {
+    prune_os_with(h, tree, &())
+}
+
+/// [`prune_os`] reporting the origin of every edge of G_t+1 and T to `witness`
+pub fn prune_os_with<N: NodeTrait + Debug, W: Witness<N>>(
+    h: ConcurrentDiGraph<N>,
+    tree: ConcurrentDiGraph<N>,
+    witness: &W,
 ) -> (ConcurrentDiGraph<N>, ConcurrentDiGraph<N>) {
     //get outgoing neighborhoods
     let outgoing_neighborhoods = h.get_neighborhoods(true);
@@ -213,6 +275,7 @@ pub fn prune_os<N: NodeTrait + Debug>(
             neighbors.iter().for_each(|v| {
                 if *v != v_min {
                     pruned_graph.add_edge(*v, v_min);
+                    witness.derived(*v, v_min, *u);
                 }
             });
         }
@@ -226,6 +289,7 @@ pub fn prune_os<N: NodeTrait + Debug>(
 
             let v_min = *v_min_opt.unwrap();
             tree.add_edge(v_min, *u);
+            witness.tree_edge(v_min, *u);
 
             deactivated_nodes.insert(*u);   //TODO: remove here instead of collecting?
         }
@@ -279,8 +343,8 @@ pub fn seed_propagation<V: NodeTrait + Debug>(tree: &ConcurrentDiGraph<V>) -> Ha
         incoming_edge.iter().for_each(|from| {
             //eprintln!("Node {:?}, edge {:?}", min_node, edge);
 
-            if seeds_map.contains_key(&from) {
-                let parent_seed = seeds_map.get(&from).unwrap();
+            if seeds_map.contains_key(from) {
+                let parent_seed = seeds_map.get(from).unwrap();
                 seeds_map.insert(min_node, *parent_seed);
             } else {
                 seeds_map.insert(min_node, *from);
@@ -314,7 +378,7 @@ pub fn par_seed_propagation<V: NodeTrait + Debug>(tree: &ConcurrentDiGraph<V>) -
             //get root
             seeds_map.insert(n, get_root(&tree, n));
         }*/
-        seeds_map.insert(n, get_root(&tree, n));
+        seeds_map.insert(n, get_root(tree, n));
     });
 
     seeds_map
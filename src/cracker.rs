@@ -0,0 +1,395 @@
+use concurrent_graph::{ConcurrentDiGraph, ConcurrentUnGraph, GraphTrait, NodeTrait};
+use dashmap::{DashMap, DashSet};
+use rayon::prelude::*;
+use std::{collections::HashMap, fmt::Debug, str::FromStr, time::{Duration, Instant}};
+use tracing::{debug, debug_span, info, info_span, trace_span};
+
+use crate::components::Components;
+use crate::concurrentgraph_utils_rayon::{
+    min_selection_base, min_selection_base_with, min_selection_ep, min_selection_ep_with, par_seed_propagation, prune, prune_os,
+    prune_os_with, prune_with,
+};
+use crate::forest::Witnesses;
+
+/// CRACKER optimizations, one per binary
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    Base,
+    /// Edge Pruning
+    Ep,
+    /// Edge Pruning + Oblivious Seed
+    EpOs,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 3] = [Variant::Base, Variant::Ep, Variant::EpOs];
+
+    /// Name of the binary implementing the variant
+    pub fn name(&self) -> &'static str {
+        match self {
+            Variant::Base => "base",
+            Variant::Ep => "ep",
+            Variant::EpOs => "epos",
+        }
+    }
+
+    /// Build the input graph expected by the variant
+    pub fn graph<V: NodeTrait>(&self, edges: &[(V, V)]) -> InputGraph<V> {
+        match self {
+            Variant::Base | Variant::Ep => InputGraph::Undirected(undirected_graph(edges)),
+            Variant::EpOs => InputGraph::Directed(directed_graph(edges)),
+        }
+    }
+
+    /// [`Variant::graph`] also holding `nodes`, those without edges end up in components of their own
+    pub fn graph_with_nodes<V: NodeTrait>(&self, edges: &[(V, V)], nodes: &[V]) -> InputGraph<V> {
+        let graph = self.graph(edges);
+        match &graph {
+            InputGraph::Undirected(g) => nodes.iter().for_each(|&v| g.add_node(v)),
+            InputGraph::Directed(g) => nodes.iter().for_each(|&v| g.add_node(v)),
+        }
+
+        graph
+    }
+
+    /// Run CRACKER on a graph built by [`Variant::graph`]
+    pub fn crack<V: NodeTrait + Debug>(&self, graph: &InputGraph<V>) -> Cracker<V> {
+        self.crack_observed(graph, &mut ())
+    }
+
+    /// [`Variant::crack`] reporting every iteration to `observer`
+    pub fn crack_observed<V, O>(&self, graph: &InputGraph<V>, observer: &mut O) -> Cracker<V>
+    where
+        V: NodeTrait + Debug,
+        O: Observer<ConcurrentUnGraph<V>, V> + Observer<ConcurrentDiGraph<V>, V>,
+    {
+        match (self, graph) {
+            (Variant::Base, InputGraph::Undirected(g)) => cracker_observed(g, min_selection_base, prune, observer),
+            (Variant::Ep, InputGraph::Undirected(g)) => cracker_observed(g, min_selection_ep, prune, observer),
+            (Variant::EpOs, InputGraph::Directed(g)) => cracker_observed(g, min_selection_ep, prune_os, observer),
+            _ => panic!("{} cannot run on this graph", self.name()),
+        }
+    }
+
+    /// [`Variant::crack_observed`] recording in `witnesses` where the edges of every G_t, H and T come from
+    pub fn crack_witnessed<V, O>(&self, graph: &InputGraph<V>, observer: &mut O, witnesses: &Witnesses<V>) -> Cracker<V>
+    where
+        V: NodeTrait + Debug,
+        O: Observer<ConcurrentUnGraph<V>, V> + Observer<ConcurrentDiGraph<V>, V>,
+    {
+        match (self, graph) {
+            (Variant::Base, InputGraph::Undirected(g)) => run(
+                g,
+                |g| min_selection_base_with(g, &witnesses.next_level()),
+                |h, t| prune_with(h, t, &witnesses.next_level()),
+                observer,
+            ),
+            (Variant::Ep, InputGraph::Undirected(g)) => run(
+                g,
+                |g| min_selection_ep_with(g, &witnesses.next_level()),
+                |h, t| prune_with(h, t, &witnesses.next_level()),
+                observer,
+            ),
+            (Variant::EpOs, InputGraph::Directed(g)) => run(
+                g,
+                |g| min_selection_ep_with(g, &witnesses.next_level()),
+                |h, t| prune_os_with(h, t, &witnesses.next_level()),
+                observer,
+            ),
+            _ => panic!("{} cannot run on this graph", self.name()),
+        }
+    }
+
+    /// Build the input graph expected by the variant and run CRACKER on it
+    pub fn run<V: NodeTrait + Debug>(&self, edges: &[(V, V)]) -> Cracker<V> {
+        self.crack(&self.graph(edges))
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Variant::ALL
+            .into_iter()
+            .find(|variant| variant.name() == s)
+            .ok_or(format!("unknown variant {s:?} (expected base, ep or epos)"))
+    }
+}
+
+/// Graph CRACKER starts from: EP+OS works on the symmetrized directed graph
+pub enum InputGraph<V: NodeTrait> {
+    Undirected(ConcurrentUnGraph<V>),
+    Directed(ConcurrentDiGraph<V>),
+}
+
+impl<V: NodeTrait> InputGraph<V> {
+    pub fn node_count(&self) -> usize {
+        match self {
+            InputGraph::Undirected(g) => g.node_count(),
+            InputGraph::Directed(g) => g.node_count(),
+        }
+    }
+}
+
+/// Result of a CRACKER run
+pub struct Cracker<V: NodeTrait> {
+    /// Propagation tree T
+    pub tree: ConcurrentDiGraph<V>,
+    /// {node: seed}
+    pub seeds: DashMap<V, V>,
+    pub iterations: usize,
+    /// Duration of the main loop (min selection + pruning), seed propagation, logging and observers excluded
+    pub main_loop: Duration,
+    /// Time spent in each phase, summed over the iterations
+    pub min_selection: Duration,
+    pub pruning: Duration,
+    pub seed_propagation: Duration,
+}
+
+impl<V: NodeTrait> Cracker<V> {
+    pub fn num_components(&self) -> usize {
+        let seeds: DashSet<V> = self.seeds.iter().map(|entry| *entry.value()).collect();
+        seeds.len()
+    }
+
+    /// Queryable view of the seed map
+    pub fn components(&self) -> Components<V> {
+        Components::new(&self.seeds)
+    }
+
+    /// {seed: #nodes of its component}
+    pub fn component_sizes(&self) -> DashMap<V, usize> {
+        let sizes: DashMap<V, usize> = DashMap::new();
+        self.seeds.iter().for_each(|entry| *sizes.entry(*entry.value()).or_insert(0) += 1);
+
+        sizes
+    }
+
+    /// Number of nodes in the biggest connected component
+    pub fn largest_component(&self) -> usize {
+        self.component_sizes().iter().map(|entry| *entry.value()).max().unwrap_or(0)
+    }
+
+    /// Height of the propagation tree T: longest root-to-node path, in edges
+    pub fn tree_depth(&self) -> usize {
+        self.node_depths().into_values().max().unwrap_or(0)
+    }
+
+    /// {node: #edges from its root in T}
+    pub fn node_depths(&self) -> HashMap<V, usize> {
+        let mut depths: HashMap<V, usize> = HashMap::with_capacity(self.tree.node_count());
+
+        for n in self.tree.nodes() {
+            // climb until a node with a known depth (or a root) is found
+            let mut chain = Vec::new();
+            let mut current = n;
+            let known = loop {
+                if let Some(&d) = depths.get(&current) {
+                    break Some(d);
+                }
+                chain.push(current);
+
+                match self.tree.incoming_edges(current).into_iter().next() {
+                    Some(parent) => current = parent,
+                    None => break None,
+                }
+            };
+
+            let first = known.map_or(0, |d| d + 1);
+            for (depth, &node) in (first..).zip(chain.iter().rev()) {
+                depths.insert(node, depth);
+            }
+        }
+
+        depths
+    }
+}
+
+pub fn undirected_graph<V: NodeTrait>(edges: &[(V, V)]) -> ConcurrentUnGraph<V> {
+    let graph = ConcurrentUnGraph::new();
+
+    for edge in edges {
+        graph.add_edge(edge.0, edge.1);
+    }
+
+    graph
+}
+
+/// Symmetrized graph: both (a -> b) and (b -> a) are added
+pub fn directed_graph<V: NodeTrait>(edges: &[(V, V)]) -> ConcurrentDiGraph<V> {
+    let graph = ConcurrentDiGraph::new();
+
+    for edge in edges {
+        graph.add_edge(edge.0, edge.1);
+        graph.add_edge(edge.1, edge.0);
+    }
+
+    graph
+}
+
+/// G_t -> H
+pub type MinSelection<G, V> = fn(&G) -> ConcurrentDiGraph<V>;
+/// (H, T) -> (G_t+1, T)
+pub type Prune<G, V> = fn(ConcurrentDiGraph<V>, ConcurrentDiGraph<V>) -> (G, ConcurrentDiGraph<V>);
+
+/// Hooks called by the main loop after every phase; the timed phases never include the hooks' own work
+pub trait Observer<G, V: NodeTrait> {
+    /// G_t and the H_t computed from it
+    fn min_selection(&mut self, _it: usize, _gt: &G, _h: &ConcurrentDiGraph<V>, _elapsed: Duration) {}
+    /// G_t+1 and T after pruning
+    fn pruning(&mut self, _it: usize, _next: &G, _tree: &ConcurrentDiGraph<V>, _elapsed: Duration) {}
+    /// {node: seed} once T has been visited
+    fn seed_propagation(&mut self, _seeds: &DashMap<V, V>, _elapsed: Duration) {}
+}
+
+impl<G, V: NodeTrait> Observer<G, V> for () {}
+
+impl<G, V: NodeTrait, A: Observer<G, V>, B: Observer<G, V>> Observer<G, V> for (A, B) {
+    fn min_selection(&mut self, it: usize, gt: &G, h: &ConcurrentDiGraph<V>, elapsed: Duration) {
+        self.0.min_selection(it, gt, h, elapsed);
+        self.1.min_selection(it, gt, h, elapsed);
+    }
+
+    fn pruning(&mut self, it: usize, next: &G, tree: &ConcurrentDiGraph<V>, elapsed: Duration) {
+        self.0.pruning(it, next, tree, elapsed);
+        self.1.pruning(it, next, tree, elapsed);
+    }
+
+    fn seed_propagation(&mut self, seeds: &DashMap<V, V>, elapsed: Duration) {
+        self.0.seed_propagation(seeds, elapsed);
+        self.1.seed_propagation(seeds, elapsed);
+    }
+}
+
+impl<G, V: NodeTrait, O: Observer<G, V>> Observer<G, V> for Option<O> {
+    fn min_selection(&mut self, it: usize, gt: &G, h: &ConcurrentDiGraph<V>, elapsed: Duration) {
+        if let Some(observer) = self {
+            observer.min_selection(it, gt, h, elapsed);
+        }
+    }
+
+    fn pruning(&mut self, it: usize, next: &G, tree: &ConcurrentDiGraph<V>, elapsed: Duration) {
+        if let Some(observer) = self {
+            observer.pruning(it, next, tree, elapsed);
+        }
+    }
+
+    fn seed_propagation(&mut self, seeds: &DashMap<V, V>, elapsed: Duration) {
+        if let Some(observer) = self {
+            observer.seed_propagation(seeds, elapsed);
+        }
+    }
+}
+
+/// Main loop: alternate min selection and pruning until G_t is empty, then propagate seeds along T
+pub fn cracker<G, V>(
+    graph: &G,
+    min_selection: MinSelection<G, V>,
+    prune: Prune<G, V>,
+) -> Cracker<V>
+where
+    G: GraphTrait<V> + Clone,
+    V: NodeTrait + Debug,
+{
+    cracker_observed(graph, min_selection, prune, &mut ())
+}
+
+/// [`cracker`] reporting every iteration to `observer`
+pub fn cracker_observed<G, V, O>(
+    graph: &G,
+    min_selection: MinSelection<G, V>,
+    prune: Prune<G, V>,
+    observer: &mut O,
+) -> Cracker<V>
+where
+    G: GraphTrait<V> + Clone,
+    V: NodeTrait + Debug,
+    O: Observer<G, V>,
+{
+    run(graph, min_selection, prune, observer)
+}
+
+fn run<G, V, O, M, P>(graph: &G, min_selection: M, prune: P, observer: &mut O) -> Cracker<V>
+where
+    G: GraphTrait<V> + Clone,
+    V: NodeTrait + Debug,
+    O: Observer<G, V>,
+    M: Fn(&G) -> ConcurrentDiGraph<V>,
+    P: Fn(ConcurrentDiGraph<V>, ConcurrentDiGraph<V>) -> (G, ConcurrentDiGraph<V>),
+{
+    let _run = info_span!("cracker", nodes = graph.node_count()).entered();
+
+    let mut gt = graph.clone();
+    let mut t = ConcurrentDiGraph::new();
+
+    let mut num_it = 0;
+    let mut min_selection_time = Duration::ZERO;
+    let mut pruning_time = Duration::ZERO;
+    // logging and observers, left out of main_loop
+    let mut hooks_time = Duration::ZERO;
+
+    let now = Instant::now();
+
+    while gt.node_count() != 0 {
+        num_it += 1;
+        let _iteration = debug_span!("iteration", it = num_it, active_nodes = gt.node_count()).entered();
+
+        //min selection
+        let span = trace_span!("min_selection").entered();
+        let phase = Instant::now();
+        let h = min_selection(&gt);
+        let elapsed = phase.elapsed();
+        min_selection_time += elapsed;
+        let hooks = Instant::now();
+        debug!(phase = "min_selection", elapsed = ?elapsed, h_edges = h.edge_count(), since_start = ?now.elapsed());
+        observer.min_selection(num_it, &gt, &h, elapsed);
+        hooks_time += hooks.elapsed();
+        span.exit();
+
+        //pruning
+        let span = trace_span!("pruning").entered();
+        let phase = Instant::now();
+        let (temp_g, tree) = prune(h, t);
+        let elapsed = phase.elapsed();
+        pruning_time += elapsed;
+        let hooks = Instant::now();
+        debug!(
+            phase = "pruning", elapsed = ?elapsed, next_active_nodes = temp_g.node_count(), tree_edges = tree.edge_count(),
+            since_start = ?now.elapsed()
+        );
+        observer.pruning(num_it, &temp_g, &tree, elapsed);
+        hooks_time += hooks.elapsed();
+        span.exit();
+
+        gt = temp_g;
+        t = tree;
+    }
+
+    let main_loop = now.elapsed().saturating_sub(hooks_time);
+
+    let span = debug_span!("seed_propagation").entered();
+    let phase = Instant::now();
+    let seeds = par_seed_propagation(&t);
+    // nodes without edges (or with self-loops only) never reach T: each is the root of its own component
+    graph.nodes().into_par_iter().filter(|v| !seeds.contains_key(v)).for_each(|v| {
+        t.add_node(v);
+        seeds.insert(v, v);
+    });
+    let seed_propagation = phase.elapsed();
+    debug!(phase = "seed_propagation", elapsed = ?seed_propagation);
+    observer.seed_propagation(&seeds, seed_propagation);
+    span.exit();
+
+    info!(iterations = num_it, main_loop = ?main_loop, duration = ?now.elapsed(), "cracker done");
+
+    Cracker {
+        tree: t,
+        seeds,
+        iterations: num_it,
+        main_loop,
+        min_selection: min_selection_time,
+        pruning: pruning_time,
+        seed_propagation,
+    }
+}
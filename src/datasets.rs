@@ -0,0 +1,56 @@
+use std::{
+    fs,
+    io::{Error, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Bundled graph and its connected components, as computed by union-find (see tests/golden.rs)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dataset {
+    /// Path relative to the manifest directory
+    pub file: String,
+    /// Nodes appearing in the edge list (isolated nodes of the header are not counted)
+    pub nodes: usize,
+    pub edges: usize,
+    pub components: usize,
+    pub largest_component: usize,
+}
+
+impl Dataset {
+    pub fn path(&self, dir: &Path) -> PathBuf {
+        dir.join(&self.file)
+    }
+}
+
+/// Parse a manifest csv (`file,nodes,edges,components,largest_component`)
+pub fn read_manifest(filename: &Path) -> Result<Vec<Dataset>, Error> {
+    let content = fs::read_to_string(filename)?;
+
+    content
+        .lines()
+        .skip(1) //skip header
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<Dataset, Error> {
+    let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+    if parts.len() != 5 {
+        return Err(invalid(line));
+    }
+
+    let num = |s: &str| s.parse::<usize>().map_err(|_| invalid(line));
+
+    Ok(Dataset {
+        file: parts[0].to_string(),
+        nodes: num(parts[1])?,
+        edges: num(parts[2])?,
+        components: num(parts[3])?,
+        largest_component: num(parts[4])?,
+    })
+}
+
+fn invalid(line: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("malformed manifest line: {line:?}"))
+}
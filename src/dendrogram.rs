@@ -0,0 +1,118 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    io::{Error, Write},
+};
+
+use concurrent_graph::NodeTrait;
+
+use crate::components::Components;
+use crate::cracker::Variant;
+use crate::weighted::WeightedEdge;
+
+/// Two clusters joined at `threshold`; clusters are named by their smallest node, so the result is named `left`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Merge<V> {
+    pub threshold: f64,
+    pub left: V,
+    pub right: V,
+    /// Nodes of the merged cluster
+    pub size: usize,
+}
+
+/// Distinct weights in decreasing order: the thresholds at which single linkage merges clusters
+pub fn distinct_weights<V>(edges: &[WeightedEdge<V>]) -> Vec<f64> {
+    let mut weights: Vec<f64> = edges.iter().map(|e| e.2).collect();
+    weights.sort_unstable_by(|a, b| b.total_cmp(a));
+    weights.dedup();
+    weights
+}
+
+/// `levels` of the [`distinct_weights`] at evenly spaced ranks, down to the smallest one: a coarse dendrogram in `levels` CRACKER runs,
+/// every merge is reported at the first level at or below its exact height
+pub fn quantile_weights<V>(edges: &[WeightedEdge<V>], levels: usize) -> Vec<f64> {
+    let weights = distinct_weights(edges);
+    if levels >= weights.len() {
+        return weights;
+    }
+
+    (1..=levels).map(|k| weights[k * weights.len() / levels - 1]).collect()
+}
+
+/// Single-linkage dendrogram as a merge table, lowering the threshold through `thresholds` (sorted decreasingly).
+/// Every step only runs `variant` on the edges entering at that threshold, contracted to the current clusters;
+/// clusters joined in the same step are merged into the smallest one in increasing order; a NaN or infinite weight or threshold is an error
+pub fn dendrogram<V>(edges: &[WeightedEdge<V>], thresholds: &[f64], variant: Variant) -> Result<Vec<Merge<V>>, String>
+where
+    V: NodeTrait + Debug,
+{
+    if let Some((a, b, w)) = edges.iter().find(|e| !e.2.is_finite()) {
+        return Err(format!("edge ({a:?}, {b:?}) has weight {w}"));
+    }
+    if let Some(t) = thresholds.iter().find(|t| !t.is_finite()) {
+        return Err(format!("invalid threshold {t}"));
+    }
+
+    let mut edges: Vec<&WeightedEdge<V>> = edges.iter().collect();
+    edges.sort_unstable_by(|a, b| b.2.total_cmp(&a.2));
+
+    // {node: cluster}, {cluster: members}; nodes appear once an edge reaches them
+    let mut cluster: HashMap<V, V> = HashMap::new();
+    let mut members: HashMap<V, Vec<V>> = HashMap::new();
+    let mut merges = Vec::new();
+    let mut next = 0;
+
+    for &threshold in thresholds {
+        let entering = edges[next..].iter().take_while(|e| e.2 >= threshold).count();
+        let mut contracted: Vec<(V, V)> = edges[next..next + entering]
+            .iter()
+            .map(|&&(a, b, _)| {
+                for v in [a, b] {
+                    cluster.entry(v).or_insert_with(|| {
+                        members.insert(v, vec![v]);
+                        v
+                    });
+                }
+                (cluster[&a].min(cluster[&b]), cluster[&a].max(cluster[&b]))
+            })
+            .filter(|(a, b)| a != b)
+            .collect();
+        next += entering;
+
+        contracted.sort_unstable();
+        contracted.dedup();
+        if contracted.is_empty() {
+            continue;
+        }
+
+        let joined = Components::new(&variant.run(&contracted).seeds);
+        for group in &joined {
+            let mut group = group.to_vec();
+            group.sort_unstable();
+            let (left, rest) = (group[0], &group[1..]);
+
+            for &right in rest {
+                let absorbed = members.remove(&right).unwrap_or_default();
+                for &v in &absorbed {
+                    cluster.insert(v, left);
+                }
+
+                let target = members.entry(left).or_default();
+                target.extend(absorbed);
+                merges.push(Merge { threshold, left, right, size: target.len() });
+            }
+        }
+    }
+
+    Ok(merges)
+}
+
+/// Write the merges as `step,threshold,left,right,size` csv rows
+pub fn write_merges<W: Write, V: Display>(mut out: W, merges: &[Merge<V>]) -> Result<(), Error> {
+    writeln!(out, "step,threshold,left,right,size")?;
+    for (step, m) in merges.iter().enumerate() {
+        writeln!(out, "{},{},{},{},{}", step + 1, m.threshold, m.left, m.right, m.size)?;
+    }
+
+    out.flush()
+}
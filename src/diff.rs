@@ -0,0 +1,76 @@
+use getopts::Options;
+use graph_example::compare::{compare, read_labels};
+
+use std::{fs::File, io::{self, Write}, process};
+
+fn main() {
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("o", "output", "write the report to a file instead of stdout", "FILEPATH");
+    opts.optopt("", "format", "output format (default text)", "text|json");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            eprintln!("{fail}");
+            process::exit(2);
+        }
+    };
+
+    //handle -h/--help, a wrong number of files is a usage error
+    let brief = format!(
+        "Usage: {} LEFT RIGHT [options]\nCompare two `node label` files written by --labels, exit status 1 if the partitions differ, 2 on errors",
+        args[0]
+    );
+    if matches.opt_present("h") {
+        print!("{}", opts.usage(&brief));
+        return;
+    }
+    if matches.free.len() != 2 {
+        eprint!("{}", opts.usage(&brief));
+        process::exit(2);
+    }
+
+    let (left, right) = match (read_labels(&matches.free[0]), read_labels(&matches.free[1])) {
+        (Ok(left), Ok(right)) => (left, right),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("Error reading labels: {e}");
+            process::exit(2);
+        }
+    };
+
+    let comparison = compare(&left, &right);
+
+    let mut out: Box<dyn Write> = match matches.opt_str("o") {
+        Some(path) => match File::create(&path) {
+            Ok(f) => Box::new(f),
+            Err(e) => {
+                eprintln!("Error creating {path}: {e}");
+                process::exit(2);
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    let written = match matches.opt_str("format").as_deref() {
+        None | Some("text") => write!(out, "{comparison}"),
+        Some("json") => serde_json::to_writer_pretty(&mut out, &comparison).map_err(io::Error::from),
+        Some(other) => {
+            eprintln!("Unknown format {other:?}");
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = written {
+        eprintln!("Error writing the report: {e}");
+        process::exit(2);
+    }
+
+    if !comparison.identical {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+use concurrent_graph::{ConcurrentDiGraph, GraphTrait, NodeTrait};
+use dashmap::DashMap;
+use rayon::prelude::*;
+
+use crate::concurrentgraph_utils_rayon::{min_selection_ep, prune_os};
+use crate::cracker::{cracker, Cracker};
+
+/// Directed graph with the arcs as given: (a -> b) only
+pub fn arc_graph<V: NodeTrait>(edges: &[(V, V)]) -> ConcurrentDiGraph<V> {
+    let graph = ConcurrentDiGraph::new();
+
+    for edge in edges {
+        graph.add_edge(edge.0, edge.1);
+    }
+
+    graph
+}
+
+/// Weakly connected components: CRACKER with EP + OS on the arcs as given.
+/// Min selection already joins incoming and outgoing neighborhoods, so the symmetrized graph is never built
+pub fn weakly_connected_components<V: NodeTrait + Debug>(graph: &ConcurrentDiGraph<V>) -> Cracker<V> {
+    cracker(graph, min_selection_ep, prune_os)
+}
+
+/// Strongly connected components as {node: smallest node of its component}, by coloring.
+/// Every round propagates the smallest color along the arcs until it is stable, then every node that kept its
+/// own color collects its component with a backward search restricted to its color, and the components leave the graph
+pub fn strongly_connected_components<V: NodeTrait>(graph: &ConcurrentDiGraph<V>) -> DashMap<V, V> {
+    let mut nodes = graph.nodes();
+    nodes.par_sort_unstable();
+    let index: HashMap<V, usize> = nodes.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+    // indices follow the node order, so the smallest color is the smallest node
+    let adjacency = |outgoing: bool| -> Vec<Vec<usize>> {
+        let neighborhoods = graph.get_neighborhoods(outgoing);
+        nodes
+            .par_iter()
+            .map(|v| neighborhoods.get(v).map_or(Vec::new(), |n| n.iter().map(|u| index[u]).collect()))
+            .collect()
+    };
+    let incoming = adjacency(false);
+
+    let mut component = vec![usize::MAX; nodes.len()];
+    let mut active: Vec<usize> = (0..nodes.len()).collect();
+
+    while !active.is_empty() {
+        let mut color: Vec<usize> = (0..nodes.len()).collect();
+
+        // pull the smallest color of the active predecessors until no color changes
+        loop {
+            let next: Vec<(usize, usize)> = active
+                .par_iter()
+                .filter_map(|&v| {
+                    let min = incoming[v].iter().filter(|&&u| component[u] == usize::MAX).map(|&u| color[u]).min()?;
+                    (min < color[v]).then_some((v, min))
+                })
+                .collect();
+
+            if next.is_empty() {
+                break;
+            }
+            for (v, c) in next {
+                color[v] = color[v].min(c);
+            }
+        }
+
+        let found: Vec<(usize, Vec<usize>)> = active
+            .par_iter()
+            .filter(|&&root| color[root] == root)
+            .map(|&root| {
+                let mut members = vec![root];
+                let mut visited: HashSet<usize> = HashSet::from([root]);
+                let mut i = 0;
+
+                while i < members.len() {
+                    for &u in &incoming[members[i]] {
+                        if component[u] == usize::MAX && color[u] == root && visited.insert(u) {
+                            members.push(u);
+                        }
+                    }
+                    i += 1;
+                }
+
+                (root, members)
+            })
+            .collect();
+
+        for (root, members) in found {
+            for v in members {
+                component[v] = root;
+            }
+        }
+        active.retain(|&v| component[v] == usize::MAX);
+    }
+
+    nodes.par_iter().zip(component.par_iter()).map(|(&v, &c)| (v, nodes[c])).collect()
+}
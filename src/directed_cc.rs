@@ -0,0 +1,110 @@
+use getopts::Options;
+use graph_example::components::{write_components, write_labels, ComponentReport, ComponentStats, Components, Labeling};
+use graph_example::directed::{arc_graph, strongly_connected_components, weakly_connected_components};
+use graph_example::logging;
+use io_util::prelude::read_from_file;
+
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+    time::Instant,
+};
+
+use rayon::ThreadPoolBuilder;
+
+fn main() {
+    type V = u32;
+
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("f", "file", "provide the file containg the graph, every entry is an arc", "FILEPATH");
+    opts.optflag("", "strong", "compute strongly connected components (default weakly)");
+    opts.optopt("", "components", "write component statistics, json for .json files, text otherwise (- = stdout)", "FILEPATH");
+    opts.optopt("", "labels", "write a `node component` line per node, sorted by node (- = stdout)", "FILEPATH");
+    opts.optopt("", "canonical", "component label written by --labels (default seed)", "seed|min|dense-min|dense-size");
+    opts.optopt("n", "num_thread", "provide the number of threads to use", "0");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -f FILE [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    //handle -n/--num_threads
+    let num_threads = match matches.opt_str("n") {
+        None => 0,  //let rayon decide
+        Some(v) => v.parse().unwrap(),
+    };
+
+    //setup parallelism
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()
+        .unwrap();
+
+    let Some(filename) = matches.opt_str("f") else {
+        eprintln!("Please provide a filename");
+        return;
+    };
+    let labeling: Labeling = match matches.opt_str("canonical").map(|l| l.parse()) {
+        None => Labeling::default(),
+        Some(Ok(labeling)) => labeling,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let edges = match read_from_file::<V>(&filename) {
+        Ok(edges) => edges,
+        Err(e) => {
+            eprintln!("Error reading edges from file: {e}");
+            return;
+        }
+    };
+    let graph = arc_graph(&edges);
+
+    let now = Instant::now();
+    let (kind, components) = if matches.opt_present("strong") {
+        ("strong", Components::new(&strongly_connected_components(&graph)))
+    } else {
+        ("weak", weakly_connected_components(&graph).components())
+    };
+    println!("{}", now.elapsed().as_millis());
+
+    if let Some(components_file) = matches.opt_str("components") {
+        let stats = ComponentStats::new(&components.sizes().collect::<Vec<_>>());
+        let report = ComponentReport { file: &filename, variant: kind, stats: &stats };
+
+        if let Err(e) = write_components(&components_file, &report) {
+            eprintln!("Error writing components: {e}");
+        }
+    }
+
+    if let Some(labels_file) = matches.opt_str("labels") {
+        let written = match labels_file.as_str() {
+            "-" => write_labels(io::stdout().lock(), components, labeling),
+            _ => File::create(&labels_file).and_then(|f| write_labels(BufWriter::new(f), components, labeling)),
+        };
+
+        if let Err(e) = written {
+            eprintln!("Error writing labels: {e}");
+        }
+    }
+}
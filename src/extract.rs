@@ -0,0 +1,135 @@
+use getopts::Options;
+use graph_example::cracker::Variant;
+use graph_example::logging;
+use graph_example::subgraphs::{extract, giant, select, write_components, write_giant};
+use io_util::prelude::{read_from_file, GraphFormat};
+
+use std::path::Path;
+
+use rayon::ThreadPoolBuilder;
+
+fn main() {
+    type V = u32;
+
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("f", "file", "provide the file containg the graph", "FILEPATH");
+    opts.optopt("o", "output", "directory receiving one file per component + index.csv", "DIR");
+    opts.optopt("", "format", "output format, mtx files are relabelled 1..n (default mtx)", "mtx|el");
+    opts.optopt("", "min_size", "skip the components with fewer nodes (default 1)", "N");
+    opts.optopt("k", "top", "only extract the k largest components", "K");
+    opts.optflag("", "giant", "only write the largest component (giant.mtx|el) and its node list (giant.nodes)");
+    opts.optopt("", "variant", "variant computing the components (default ep)", "base|ep|epos");
+    opts.optopt("n", "num_thread", "provide the number of threads to use", "0");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -f FILE -o DIR [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    //handle -n/--num_threads
+    let num_threads = match matches.opt_str("n") {
+        None => 0,  //let rayon decide
+        Some(v) => v.parse().unwrap(),
+    };
+
+    //setup parallelism
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()
+        .unwrap();
+
+    let (filename, output) = match (matches.opt_str("f"), matches.opt_str("o")) {
+        (Some(f), Some(o)) => (f, o),
+        _ => {
+            eprintln!("Please provide a filename and an output directory");
+            return;
+        }
+    };
+
+    let format = match matches.opt_str("format").map(|f| f.parse::<GraphFormat>()) {
+        None => GraphFormat::MatrixMarket,
+        Some(Ok(format)) => format,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+    let variant = match matches.opt_str("variant").map(|v| v.parse::<Variant>()) {
+        None => Variant::Ep,
+        Some(Ok(variant)) => variant,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+    let min_size: usize = match matches.opt_str("min_size").map(|v| v.parse()) {
+        None => 1,
+        Some(Ok(min_size)) => min_size,
+        Some(Err(_)) => {
+            eprintln!("Invalid min_size");
+            return;
+        }
+    };
+    let top: Option<usize> = match matches.opt_str("k").map(|v| v.parse()) {
+        None => None,
+        Some(Ok(top)) => Some(top),
+        Some(Err(_)) => {
+            eprintln!("Invalid top");
+            return;
+        }
+    };
+
+    let edges = match read_from_file::<V>(&filename) {
+        Ok(edges) => edges,
+        Err(e) => {
+            eprintln!("Error reading edges from file: {e}");
+            return;
+        }
+    };
+
+    let graph = variant.graph(&edges);
+    let res = variant.crack(&graph);
+
+    if matches.opt_present("giant") {
+        let Some(giant) = giant(&graph, &res.seeds, &res.component_sizes()) else {
+            eprintln!("Empty graph");
+            return;
+        };
+
+        if let Err(e) = write_giant(Path::new(&output), &giant, format) {
+            eprintln!("Error writing the giant component: {e}");
+            return;
+        }
+
+        eprintln!("{output}: giant component, {} of {} nodes", giant.nodes.len(), graph.node_count());
+        return;
+    }
+
+    let selected: Vec<V> = select(&res.component_sizes(), min_size, top).into_iter().map(|(seed, _)| seed).collect();
+    let subgraphs = extract(&graph, &res.seeds, &selected);
+
+    if let Err(e) = write_components(Path::new(&output), &subgraphs, format) {
+        eprintln!("Error writing components: {e}");
+        return;
+    }
+
+    eprintln!("{output}: {} of {} components", subgraphs.len(), res.num_components());
+}
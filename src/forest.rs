@@ -0,0 +1,138 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use concurrent_graph::NodeTrait;
+use dashmap::{DashMap, DashSet};
+use rayon::prelude::*;
+
+use crate::components::Components;
+use crate::concurrentgraph_utils_rayon::Witness;
+use crate::union_find::UnionFind;
+
+/// {edge (min, max): via} of one level
+type Vias<V> = Arc<DashMap<(V, V), V>>;
+
+/// Origin of the edges CRACKER derives, level by level: the input graph is level 0, then H_1, G_2, H_2, G_3, ...
+/// Every derived edge a - b keeps a node `via` such that a - via and via - b are edges of the level below (or a == via, via == b),
+/// so an edge of T, taken from an edge of H, stands for a path of input edges
+pub struct Witnesses<V: NodeTrait> {
+    /// Levels 1, 2, ...
+    levels: Mutex<Vec<Vias<V>>>,
+    /// {child: (parent, level of the H edge child -> parent)}
+    tree: DashMap<V, (V, usize)>,
+}
+
+/// One level being recorded, handed to min selection or pruning
+pub struct Level<'a, V: NodeTrait> {
+    index: usize,
+    edges: Vias<V>,
+    tree: &'a DashMap<V, (V, usize)>,
+}
+
+impl<V: NodeTrait> Witness<V> for Level<'_, V> {
+    #[inline]
+    fn derived(&self, a: V, b: V, via: V) {
+        if a != b {
+            self.edges.insert((a.min(b), a.max(b)), via);
+        }
+    }
+
+    /// Pruning runs on the level after the H it reads
+    #[inline]
+    fn tree_edge(&self, parent: V, child: V) {
+        self.tree.insert(child, (parent, self.index - 1));
+    }
+}
+
+impl<V: NodeTrait> Default for Witnesses<V> {
+    fn default() -> Self {
+        Witnesses { levels: Mutex::new(Vec::new()), tree: DashMap::new() }
+    }
+}
+
+impl<V: NodeTrait> Witnesses<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start recording the next level: called before every min selection and every pruning
+    pub fn next_level(&self) -> Level<'_, V> {
+        let mut levels = self.levels.lock().unwrap();
+        levels.push(Arc::new(DashMap::new()));
+
+        Level { index: levels.len(), edges: levels[levels.len() - 1].clone(), tree: &self.tree }
+    }
+
+    /// Spanning forest of the input graph: the input edges behind the edges of T, minus those closing a cycle, sorted.
+    /// The paths behind the edges of T join every node to its parent, so they span every component
+    pub fn spanning_forest(&self) -> Vec<(V, V)> {
+        let levels = self.levels.lock().unwrap().clone();
+        let visited: DashSet<(usize, V, V)> = DashSet::new();
+        let behind: DashSet<(V, V)> = DashSet::new();
+
+        self.tree.par_iter().for_each(|entry| {
+            let (&child, &(parent, level)) = entry.pair();
+            expand(&levels, child, parent, level, &visited, &behind);
+        });
+
+        let mut edges: Vec<(V, V)> = behind.into_iter().collect();
+        edges.par_sort_unstable();
+
+        // Kruskal on unit weights: keep the edges joining two trees
+        let index: HashMap<V, usize> = edges.iter().flat_map(|&(a, b)| [a, b]).collect::<HashSet<V>>().into_iter().zip(0..).collect();
+        let mut sets = UnionFind::new(index.len());
+        edges.retain(|(a, b)| sets.union(index[a], index[b]));
+
+        edges
+    }
+}
+
+/// Collect in `behind` the input edges of the path standing for the edge a - b of `level`
+fn expand<V: NodeTrait>(levels: &[Vias<V>], a: V, b: V, level: usize, visited: &DashSet<(usize, V, V)>, behind: &DashSet<(V, V)>) {
+    let edge = (a.min(b), a.max(b));
+    if a == b || !visited.insert((level, edge.0, edge.1)) {
+        return;
+    }
+    if level == 0 {
+        behind.insert(edge);
+        return;
+    }
+
+    let via = *levels[level - 1].get(&edge).expect("every derived edge has a witness");
+    expand(levels, a, via, level - 1, visited, behind);
+    expand(levels, via, b, level - 1, visited, behind);
+}
+
+/// Check that `forest` only uses edges of `input` and spans every component of `components` without cycles
+pub fn verify_forest<V>(forest: &[(V, V)], input: &[(V, V)], components: &Components<V>) -> Result<(), String>
+where V: NodeTrait + std::fmt::Debug,
+{
+    let input: HashSet<(V, V)> = input.iter().map(|&(a, b)| (a.min(b), a.max(b))).collect();
+    let mut sets = UnionFind::new(components.num_nodes());
+
+    for &(a, b) in forest {
+        if !input.contains(&(a.min(b), a.max(b))) {
+            return Err(format!("({a:?}, {b:?}) is not an input edge"));
+        }
+
+        let (Some(i), Some(j)) = (components.index_of(a), components.index_of(b)) else {
+            return Err(format!("({a:?}, {b:?}) has an unknown endpoint"));
+        };
+        if !sets.union(i, j) {
+            return Err(format!("({a:?}, {b:?}) closes a cycle"));
+        }
+    }
+
+    let expected = components.num_nodes() - components.len();
+    if forest.len() != expected {
+        return Err(format!("{} edges, a spanning forest has {expected}", forest.len()));
+    }
+
+    // n - k acyclic edges inside the components => one tree per component
+    match components.iter().find(|members| sets.size(components.index_of(members[0]).unwrap()) != members.len()) {
+        Some(members) => Err(format!("the component of {:?} is not spanned", members[0])),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,84 @@
+use getopts::Options;
+use graph_example::generators::Generator;
+use io_util::prelude::{write_to_file, GraphFormat};
+
+fn main() {
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("g", "graph", "generator and its parameters: er:n,m | rmat:scale,m[,a,b,c] | ba:n,k | grid:rows,cols | star:n | path:n | zigzag:n | caterpillar:spine,legs | comb:teeth,length | planted:n,m,components", "SPEC");
+    opts.optopt("s", "seed", "random seed (default 0)", "N");
+    opts.optopt("o", "output", "output file", "FILEPATH");
+    opts.optopt("", "format", "output format (default: from the extension, .mtx => mtx)", "mtx|el");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -g SPEC -o FILE [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    let generator: Generator = match matches.opt_str("g").map(|spec| spec.parse()) {
+        Some(Ok(generator)) => generator,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+        None => {
+            eprintln!("Please provide a generator");
+            return;
+        }
+    };
+
+    let seed: u64 = match matches.opt_str("s").map(|s| s.parse()) {
+        None => 0,
+        Some(Ok(seed)) => seed,
+        Some(Err(_)) => {
+            eprintln!("Invalid seed");
+            return;
+        }
+    };
+
+    let output = match matches.opt_str("o") {
+        Some(output) => output,
+        None => {
+            eprintln!("Please provide an output file");
+            return;
+        }
+    };
+
+    let format = match matches.opt_str("format").map(|f| f.parse()) {
+        None => GraphFormat::from_path(&output),
+        Some(Ok(format)) => format,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let graph = match generator.generate(seed) {
+        Ok(graph) => graph,
+        Err(e) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    if let Err(e) = write_to_file(&output, &graph.edges, graph.num_nodes, format) {
+        eprintln!("Error writing {output}: {e}");
+        return;
+    }
+
+    eprintln!("{output}: {} nodes, {} edges", graph.num_nodes, graph.edges.len());
+}
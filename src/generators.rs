@@ -0,0 +1,425 @@
+use std::{collections::HashSet, str::FromStr};
+
+type V = u32;
+
+/// SplitMix64: tiny and stable across releases, so a seed always yields the same graph
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, n)
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Uniform in [0, 1)
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generated edge list; nodes are labelled 0..num_nodes, isolated ones do not appear in `edges`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntheticGraph {
+    pub num_nodes: usize,
+    pub edges: Vec<(V, V)>,
+}
+
+/// Undirected edge set: rejects self-loops and parallel edges, keeps insertion order
+struct EdgeSet {
+    seen: HashSet<(V, V)>,
+    edges: Vec<(V, V)>,
+}
+
+impl EdgeSet {
+    fn with_capacity(capacity: usize) -> Self {
+        EdgeSet { seen: HashSet::with_capacity(capacity), edges: Vec::with_capacity(capacity) }
+    }
+
+    fn insert(&mut self, a: usize, b: usize) -> bool {
+        if a == b {
+            return false;
+        }
+
+        let (a, b) = (a as V, b as V);
+        let inserted = self.seen.insert((a.min(b), a.max(b)));
+        if inserted {
+            self.edges.push((a, b));
+        }
+
+        inserted
+    }
+
+    fn len(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+fn max_edges(num_nodes: usize) -> usize {
+    num_nodes * num_nodes.saturating_sub(1) / 2
+}
+
+fn check_nodes(num_nodes: usize) -> Result<(), String> {
+    if num_nodes > V::MAX as usize {
+        return Err(format!("{num_nodes} nodes do not fit in u32 labels"));
+    }
+    Ok(())
+}
+
+/// Uniform random graph with exactly `num_edges` edges (G(n, m))
+pub fn erdos_renyi(num_nodes: usize, num_edges: usize, rng: &mut Rng) -> Result<SyntheticGraph, String> {
+    check_nodes(num_nodes)?;
+    if num_edges > max_edges(num_nodes) {
+        return Err(format!("G({num_nodes}, {num_edges}): too many edges"));
+    }
+
+    let mut edges = EdgeSet::with_capacity(num_edges);
+    while edges.len() < num_edges {
+        edges.insert(rng.below(num_nodes), rng.below(num_nodes));
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges: edges.edges })
+}
+
+/// R-MAT on 2^scale nodes: every edge recursively picks one of the adjacency quadrants
+/// with probabilities (a, b, c, 1 - a - b - c)
+pub fn rmat(scale: u32, num_edges: usize, (a, b, c): (f64, f64, f64), rng: &mut Rng) -> Result<SyntheticGraph, String> {
+    if scale >= V::BITS {
+        return Err(format!("scale {scale} does not fit in u32 labels"));
+    }
+    if a < 0.0 || b < 0.0 || c < 0.0 || a + b + c > 1.0 {
+        return Err(format!("invalid R-MAT probabilities ({a}, {b}, {c})"));
+    }
+
+    let num_nodes = 1usize << scale;
+    if num_edges > max_edges(num_nodes) {
+        return Err(format!("R-MAT({scale}, {num_edges}): too many edges"));
+    }
+
+    let mut edges = EdgeSet::with_capacity(num_edges);
+    let max_attempts = 100 * num_edges.max(1);
+    let mut attempts = 0;
+
+    while edges.len() < num_edges {
+        if attempts == max_attempts {
+            return Err(format!("R-MAT({scale}, {num_edges}): too many duplicates, lower the skew or the edges"));
+        }
+        attempts += 1;
+
+        let (mut u, mut v) = (0, 0);
+        for bit in (0..scale).rev() {
+            let p = rng.unit();
+            let (row, col) = if p < a {
+                (0, 0)
+            } else if p < a + b {
+                (0, 1)
+            } else if p < a + b + c {
+                (1, 0)
+            } else {
+                (1, 1)
+            };
+            u |= row << bit;
+            v |= col << bit;
+        }
+
+        edges.insert(u, v);
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges: edges.edges })
+}
+
+/// Preferential attachment: every new node links to `attach` distinct nodes, chosen proportionally to their degree
+pub fn barabasi_albert(num_nodes: usize, attach: usize, rng: &mut Rng) -> Result<SyntheticGraph, String> {
+    check_nodes(num_nodes)?;
+    if attach == 0 || attach >= num_nodes {
+        return Err(format!("BA({num_nodes}, {attach}): attach must be in [1, nodes)"));
+    }
+
+    let mut edges = EdgeSet::with_capacity(attach * (num_nodes - attach));
+    // every node appears once per incident edge => uniform picks are degree-proportional
+    let mut repeated: Vec<usize> = Vec::with_capacity(2 * attach * (num_nodes - attach));
+    let mut targets: Vec<usize> = (0..attach).collect();
+
+    for source in attach..num_nodes {
+        for &t in &targets {
+            edges.insert(source, t);
+        }
+        repeated.extend(&targets);
+        repeated.extend(std::iter::repeat_n(source, attach));
+
+        let mut chosen: HashSet<usize> = HashSet::with_capacity(attach);
+        targets.clear();
+        while targets.len() < attach {
+            let t = repeated[rng.below(repeated.len())];
+            if chosen.insert(t) {
+                targets.push(t);
+            }
+        }
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges: edges.edges })
+}
+
+/// rows x cols lattice, node (r, c) is labelled r * cols + c
+pub fn grid(rows: usize, cols: usize) -> Result<SyntheticGraph, String> {
+    let num_nodes = rows.checked_mul(cols).ok_or_else(|| format!("{rows} x {cols} nodes do not fit in u32 labels"))?;
+    check_nodes(num_nodes)?;
+
+    let mut edges = Vec::with_capacity(2 * num_nodes);
+    for r in 0..rows {
+        for c in 0..cols {
+            let n = (r * cols + c) as V;
+            if c + 1 < cols {
+                edges.push((n, n + 1));
+            }
+            if r + 1 < rows {
+                edges.push((n, n + cols as V));
+            }
+        }
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges })
+}
+
+/// Node 0 linked to every other node
+pub fn star(num_nodes: usize) -> Result<SyntheticGraph, String> {
+    check_nodes(num_nodes)?;
+    let edges = (1..num_nodes as V).map(|leaf| (0, leaf)).collect();
+
+    Ok(SyntheticGraph { num_nodes, edges })
+}
+
+/// 0 - 1 - ... - (n-1)
+pub fn path(num_nodes: usize) -> Result<SyntheticGraph, String> {
+    check_nodes(num_nodes)?;
+    let edges = (1..num_nodes as V).map(|n| (n - 1, n)).collect();
+
+    Ok(SyntheticGraph { num_nodes, edges })
+}
+
+/// Path visiting the labels alternately from both ends: 0 - (n-1) - 1 - (n-2) - ...
+pub fn zigzag_path(num_nodes: usize) -> Result<SyntheticGraph, String> {
+    check_nodes(num_nodes)?;
+
+    let order: Vec<V> = (0..num_nodes)
+        .map(|i| if i % 2 == 0 { i / 2 } else { num_nodes - 1 - i / 2 } as V)
+        .collect();
+    let edges = order.windows(2).map(|w| (w[0], w[1])).collect();
+
+    Ok(SyntheticGraph { num_nodes, edges })
+}
+
+/// Increasing path of `spine` nodes, each with `legs` leaves; leaves are labelled after the spine
+pub fn caterpillar(spine: usize, legs: usize) -> Result<SyntheticGraph, String> {
+    let num_nodes = legs.checked_add(1).and_then(|n| n.checked_mul(spine))
+        .ok_or_else(|| format!("{spine} x ({legs} + 1) nodes do not fit in u32 labels"))?;
+    check_nodes(num_nodes)?;
+
+    let mut edges: Vec<(V, V)> = Vec::with_capacity(num_nodes);
+    edges.extend((1..spine as V).map(|n| (n - 1, n)));
+    for s in 0..spine {
+        for l in 0..legs {
+            edges.push((s as V, (spine + s * legs + l) as V));
+        }
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges })
+}
+
+/// Increasing path of `teeth` nodes, each with a hanging increasing path of `length` nodes
+pub fn comb(teeth: usize, length: usize) -> Result<SyntheticGraph, String> {
+    let num_nodes = length.checked_add(1).and_then(|n| n.checked_mul(teeth))
+        .ok_or_else(|| format!("{teeth} x ({length} + 1) nodes do not fit in u32 labels"))?;
+    check_nodes(num_nodes)?;
+
+    let mut edges: Vec<(V, V)> = Vec::with_capacity(num_nodes);
+    edges.extend((1..teeth as V).map(|n| (n - 1, n)));
+    for t in 0..teeth {
+        let mut prev = t;
+        for l in 0..length {
+            let node = teeth + t * length + l;
+            edges.push((prev as V, node as V));
+            prev = node;
+        }
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges })
+}
+
+/// Exactly `components` connected components of (almost) equal size with `num_edges` edges in total;
+/// labels are shuffled so components are not contiguous ranges
+pub fn planted(num_nodes: usize, num_edges: usize, components: usize, rng: &mut Rng) -> Result<SyntheticGraph, String> {
+    check_nodes(num_nodes)?;
+    if components == 0 || num_nodes < 2 * components {
+        return Err(format!("planted({num_nodes}, {num_edges}, {components}): every component needs at least 2 nodes"));
+    }
+
+    // [start, end) of every component
+    let bounds: Vec<(usize, usize)> = (0..components)
+        .map(|i| (i * num_nodes / components, (i + 1) * num_nodes / components))
+        .collect();
+
+    let min_edges = num_nodes - components;
+    let capacity: usize = bounds.iter().map(|(start, end)| max_edges(end - start)).sum();
+    if num_edges < min_edges || num_edges > capacity {
+        return Err(format!("planted({num_nodes}, {num_edges}, {components}): edges must be in [{min_edges}, {capacity}]"));
+    }
+
+    let mut labels: Vec<usize> = (0..num_nodes).collect();
+    rng.shuffle(&mut labels);
+
+    let mut edges = EdgeSet::with_capacity(num_edges);
+
+    // random recursive tree => every component is connected
+    for &(start, end) in &bounds {
+        for n in start + 1..end {
+            edges.insert(labels[n], labels[start + rng.below(n - start)]);
+        }
+    }
+
+    // extra edges, endpoints uniform over the nodes => components get edges proportionally to their size
+    while edges.len() < num_edges {
+        let n = rng.below(num_nodes);
+        let (start, end) = bounds[bounds.partition_point(|&(_, end)| end <= n)];
+
+        edges.insert(labels[n], labels[start + rng.below(end - start)]);
+    }
+
+    Ok(SyntheticGraph { num_nodes, edges: edges.edges })
+}
+
+/// Generator and its parameters, parsed from `family:param,param,...`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Generator {
+    /// `er:nodes,edges`
+    ErdosRenyi { nodes: usize, edges: usize },
+    /// `rmat:scale,edges[,a,b,c]`
+    Rmat { scale: u32, edges: usize, probabilities: (f64, f64, f64) },
+    /// `ba:nodes,attach`
+    BarabasiAlbert { nodes: usize, attach: usize },
+    /// `grid:rows,cols`
+    Grid { rows: usize, cols: usize },
+    /// `star:nodes`
+    Star { nodes: usize },
+    /// `path:nodes`
+    Path { nodes: usize },
+    /// `zigzag:nodes`
+    ZigzagPath { nodes: usize },
+    /// `caterpillar:spine,legs`
+    Caterpillar { spine: usize, legs: usize },
+    /// `comb:teeth,length`
+    Comb { teeth: usize, length: usize },
+    /// `planted:nodes,edges,components`
+    Planted { nodes: usize, edges: usize, components: usize },
+}
+
+/// Graph500 R-MAT parameters
+pub const RMAT_DEFAULT: (f64, f64, f64) = (0.57, 0.19, 0.19);
+
+impl Generator {
+    pub fn generate(&self, seed: u64) -> Result<SyntheticGraph, String> {
+        let mut rng = Rng::new(seed);
+
+        match *self {
+            Generator::ErdosRenyi { nodes, edges } => erdos_renyi(nodes, edges, &mut rng),
+            Generator::Rmat { scale, edges, probabilities } => rmat(scale, edges, probabilities, &mut rng),
+            Generator::BarabasiAlbert { nodes, attach } => barabasi_albert(nodes, attach, &mut rng),
+            Generator::Grid { rows, cols } => grid(rows, cols),
+            Generator::Star { nodes } => star(nodes),
+            Generator::Path { nodes } => path(nodes),
+            Generator::ZigzagPath { nodes } => zigzag_path(nodes),
+            Generator::Caterpillar { spine, legs } => caterpillar(spine, legs),
+            Generator::Comb { teeth, length } => comb(teeth, length),
+            Generator::Planted { nodes, edges, components } => planted(nodes, edges, components, &mut rng),
+        }
+    }
+}
+
+impl FromStr for Generator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (family, params) = s.split_once(':').unwrap_or((s, ""));
+        let params: Vec<&str> = params.split(',').map(str::trim).filter(|p| !p.is_empty()).collect();
+
+        let int = |i: usize| -> Result<usize, String> {
+            let p = params.get(i).ok_or(format!("{s:?}: missing parameter #{}", i + 1))?;
+            p.replace('_', "").parse().map_err(|_| format!("{s:?}: invalid parameter {p:?}"))
+        };
+        let float = |i: usize| -> Result<f64, String> {
+            let p = params[i];
+            p.parse().map_err(|_| format!("{s:?}: invalid parameter {p:?}"))
+        };
+        let arity = |n: &[usize]| -> Result<(), String> {
+            if n.contains(&params.len()) {
+                Ok(())
+            } else {
+                Err(format!("{s:?}: expected {n:?} parameters, got {}", params.len()))
+            }
+        };
+
+        match family {
+            "er" => {
+                arity(&[2])?;
+                Ok(Generator::ErdosRenyi { nodes: int(0)?, edges: int(1)? })
+            }
+            "rmat" => {
+                arity(&[2, 5])?;
+                let probabilities = if params.len() == 5 { (float(2)?, float(3)?, float(4)?) } else { RMAT_DEFAULT };
+                let scale = u32::try_from(int(0)?).map_err(|_| format!("{s:?}: invalid scale {:?}", params[0]))?;
+                Ok(Generator::Rmat { scale, edges: int(1)?, probabilities })
+            }
+            "ba" => {
+                arity(&[2])?;
+                Ok(Generator::BarabasiAlbert { nodes: int(0)?, attach: int(1)? })
+            }
+            "grid" => {
+                arity(&[2])?;
+                Ok(Generator::Grid { rows: int(0)?, cols: int(1)? })
+            }
+            "star" => {
+                arity(&[1])?;
+                Ok(Generator::Star { nodes: int(0)? })
+            }
+            "path" => {
+                arity(&[1])?;
+                Ok(Generator::Path { nodes: int(0)? })
+            }
+            "zigzag" => {
+                arity(&[1])?;
+                Ok(Generator::ZigzagPath { nodes: int(0)? })
+            }
+            "caterpillar" => {
+                arity(&[2])?;
+                Ok(Generator::Caterpillar { spine: int(0)?, legs: int(1)? })
+            }
+            "comb" => {
+                arity(&[2])?;
+                Ok(Generator::Comb { teeth: int(0)?, length: int(1)? })
+            }
+            "planted" => {
+                arity(&[3])?;
+                Ok(Generator::Planted { nodes: int(0)?, edges: int(1)?, components: int(2)? })
+            }
+            _ => Err(format!("unknown generator {family:?} (expected er, rmat, ba, grid, star, path, zigzag, caterpillar, comb or planted)")),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::{collections::HashMap, fmt::Debug, time::Duration};
+
+use concurrent_graph::{ConcurrentDiGraph, GraphTrait, NodeTrait};
+
+use crate::cracker::Observer;
+use crate::trace::Arcs;
+use crate::union_find::UnionFind;
+
+/// Broken invariant, with the iteration and the offending nodes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Violation<V> {
+    /// Node with more than one parent in T
+    Parents { iteration: usize, node: V, parents: Vec<V> },
+    /// Edge of T closing a cycle
+    Cycle { iteration: usize, from: V, to: V },
+    /// Nodes connected in the input graph but not in G_t+1 ∪ T, or the other way round
+    Connectivity { iteration: usize, a: V, b: V, in_input: bool },
+    /// Node of G_t+1 or T missing from the input graph
+    UnknownNode { iteration: usize, node: V },
+}
+
+impl<V: Debug> std::fmt::Display for Violation<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Parents { iteration, node, parents } => {
+                write!(f, "iteration {iteration}: {node:?} has {} parents in T: {parents:?}", parents.len())
+            }
+            Violation::Cycle { iteration, from, to } => write!(f, "iteration {iteration}: {from:?} -> {to:?} closes a cycle in T"),
+            Violation::Connectivity { iteration, a, b, in_input: true } => {
+                write!(f, "iteration {iteration}: {a:?} and {b:?} are connected in G but not in G_t ∪ T")
+            }
+            Violation::Connectivity { iteration, a, b, in_input: false } => {
+                write!(f, "iteration {iteration}: {a:?} and {b:?} are connected in G_t ∪ T but not in G")
+            }
+            Violation::UnknownNode { iteration, node } => write!(f, "iteration {iteration}: {node:?} is not a node of G"),
+        }
+    }
+}
+
+/// Checks after every pruning that T is a forest (one parent per deactivated node, no cycle)
+/// and that G_t+1 ∪ T has the connected components of the input graph
+#[derive(Clone, Debug)]
+pub struct InvariantChecker<V> {
+    /// Dense id of every input node
+    index: HashMap<V, usize>,
+    nodes: Vec<V>,
+    /// Component root of every input node
+    reference: Vec<usize>,
+    /// Panic on the first violation instead of collecting it
+    pub fail_fast: bool,
+    pub violations: Vec<Violation<V>>,
+}
+
+impl<V: NodeTrait + Debug> InvariantChecker<V> {
+    pub fn new(edges: &[(V, V)]) -> Self {
+        Self::with_nodes(edges, &[])
+    }
+
+    /// [`InvariantChecker::new`] for a graph also holding `nodes`, possibly without edges
+    pub fn with_nodes(edges: &[(V, V)], nodes: &[V]) -> Self {
+        let mut nodes: Vec<V> = edges.iter().flat_map(|&(a, b)| [a, b]).chain(nodes.iter().copied()).collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        let index: HashMap<V, usize> = nodes.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+        let mut sets = UnionFind::new(nodes.len());
+        for (a, b) in edges {
+            sets.union(index[a], index[b]);
+        }
+        let reference = (0..nodes.len()).map(|i| sets.find(i)).collect();
+
+        InvariantChecker { index, nodes, reference, fail_fast: true, violations: Vec::new() }
+    }
+
+    /// Check G_t+1 and T at the end of iteration `iteration`
+    pub fn check<G: Arcs<V>>(&self, iteration: usize, next: &G, tree: &ConcurrentDiGraph<V>) -> Result<(), Violation<V>> {
+        let tree_arcs = tree.arcs();
+        let id = |node: V| self.index.get(&node).copied().ok_or(Violation::UnknownNode { iteration, node });
+
+        // one parent per node
+        let mut parents: HashMap<V, Vec<V>> = HashMap::new();
+        for &(p, v) in &tree_arcs {
+            parents.entry(v).or_default().push(p);
+        }
+        if let Some((&node, parents)) = parents.iter().filter(|(_, p)| p.len() > 1).min_by_key(|(v, _)| **v) {
+            return Err(Violation::Parents { iteration, node, parents: parents.clone() });
+        }
+
+        // with one parent per node, T has a cycle iff an edge joins two nodes already connected
+        let mut sets = UnionFind::new(self.nodes.len());
+        for &(from, to) in &tree_arcs {
+            if !sets.union(id(from)?, id(to)?) {
+                return Err(Violation::Cycle { iteration, from, to });
+            }
+        }
+
+        for (a, b) in next.arcs() {
+            sets.union(id(a)?, id(b)?);
+        }
+
+        // same partition <=> the component roots map one to one
+        let mut forward: HashMap<usize, usize> = HashMap::new();
+        let mut backward: HashMap<usize, usize> = HashMap::new();
+        for i in 0..self.nodes.len() {
+            let (reference, current) = (self.reference[i], sets.find(i));
+
+            let first = *forward.entry(reference).or_insert(i);
+            if sets.find(first) != current {
+                return Err(Violation::Connectivity { iteration, a: self.nodes[first], b: self.nodes[i], in_input: true });
+            }
+
+            let first = *backward.entry(current).or_insert(i);
+            if self.reference[first] != reference {
+                return Err(Violation::Connectivity { iteration, a: self.nodes[first], b: self.nodes[i], in_input: false });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<G, V> Observer<G, V> for InvariantChecker<V>
+where
+    G: GraphTrait<V> + Arcs<V>,
+    V: NodeTrait + Debug,
+{
+    fn pruning(&mut self, it: usize, next: &G, tree: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        if let Err(violation) = self.check(it, next, tree) {
+            if self.fail_fast {
+                panic!("invariant violated at {violation}");
+            }
+            self.violations.push(violation);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+pub mod benchmark;
+pub mod bfs;
+pub mod biconnected;
+pub mod bipartite;
+pub mod boruvka;
+pub mod cli;
+pub mod compare;
+pub mod components;
+pub mod concurrentgraph_utils_rayon;
+pub mod cracker;
+pub mod datasets;
+pub mod dendrogram;
+pub mod directed;
+pub mod forest;
+pub mod generators;
+pub mod invariants;
+pub mod logging;
+pub mod memory;
+pub mod stats;
+pub mod subgraphs;
+pub mod trace;
+pub mod tree;
+pub mod union_find;
+pub mod visual;
+pub mod weighted;
+pub mod worst_case;
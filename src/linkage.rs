@@ -0,0 +1,119 @@
+use getopts::Options;
+use graph_example::cracker::Variant;
+use graph_example::dendrogram::{dendrogram, distinct_weights, quantile_weights, write_merges};
+use graph_example::logging;
+use io_util::prelude::read_weighted_from_file;
+
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use rayon::ThreadPoolBuilder;
+
+fn main() {
+    type V = u32;
+
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("f", "file", "provide the real/integer MatrixMarket file containg the weighted graph", "FILEPATH");
+    opts.optopt("o", "output", "merge table csv (default stdout)", "FILEPATH");
+    opts.optopt("", "thresholds", "comma separated thresholds (default every distinct weight, i.e. exact single linkage: one CRACKER run per weight)", "T1,T2,...");
+    opts.optopt("", "levels", "only lower the threshold through N quantiles of the distinct weights, i.e. N CRACKER runs", "N");
+    opts.optopt("", "variant", "variant computing the components of every step (default ep)", "base|ep|epos");
+    opts.optopt("n", "num_thread", "provide the number of threads to use", "0");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -f FILE [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    //handle -n/--num_threads
+    let num_threads = match matches.opt_str("n") {
+        None => 0,  //let rayon decide
+        Some(v) => v.parse().unwrap(),
+    };
+
+    //setup parallelism
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()
+        .unwrap();
+
+    let Some(filename) = matches.opt_str("f") else {
+        eprintln!("Please provide a filename");
+        return;
+    };
+    let variant = match matches.opt_str("variant").map(|v| v.parse::<Variant>()) {
+        None => Variant::Ep,
+        Some(Ok(variant)) => variant,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let edges = match read_weighted_from_file::<V, f64>(&filename) {
+        Ok(edges) => edges,
+        Err(e) => {
+            eprintln!("Error reading edges from file: {e}");
+            return;
+        }
+    };
+
+    let thresholds: Vec<f64> = match (matches.opt_str("thresholds"), matches.opt_str("levels").map(|n| n.parse::<usize>())) {
+        (Some(_), Some(_)) => {
+            eprintln!("--thresholds and --levels are exclusive");
+            return;
+        }
+        (None, None) => distinct_weights(&edges),
+        (None, Some(Ok(levels))) if levels > 0 => quantile_weights(&edges, levels),
+        (None, Some(_)) => {
+            eprintln!("Invalid levels");
+            return;
+        }
+        (Some(list), None) => match list.split(',').map(|t| t.trim().parse::<f64>()).collect::<Result<Vec<_>, _>>() {
+            Ok(mut thresholds) => {
+                thresholds.sort_unstable_by(|a, b| b.total_cmp(a));
+                thresholds
+            }
+            Err(_) => {
+                eprintln!("Invalid thresholds");
+                return;
+            }
+        },
+    };
+
+    let merges = match dendrogram(&edges, &thresholds, variant) {
+        Ok(merges) => merges,
+        Err(e) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    let written = match matches.opt_str("o") {
+        None => write_merges(io::stdout().lock(), &merges),
+        Some(output) => File::create(&output).and_then(|f| write_merges(BufWriter::new(f), &merges)),
+    };
+    if let Err(e) = written {
+        eprintln!("Error writing merges: {e}");
+    }
+}
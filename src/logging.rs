@@ -0,0 +1,27 @@
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::{fmt, EnvFilter};
+
+/// Level enabled by passing `-v` `verbosity` times
+pub fn level(verbosity: usize) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Filter of the binaries: `RUST_LOG` when set (e.g. `RUST_LOG=graph_example=debug`), `-v` otherwise
+pub fn filter(verbosity: usize) -> EnvFilter {
+    EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level(verbosity)))
+}
+
+/// Install the global subscriber, logging to stderr so stdout only carries results
+pub fn init(verbosity: usize) {
+    let _ = fmt()
+        .with_env_filter(filter(verbosity))
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init();
+}
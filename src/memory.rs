@@ -0,0 +1,218 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fs::{self, File},
+    io::{BufWriter, Error},
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use concurrent_graph::{ConcurrentDiGraph, NodeTrait};
+use dashmap::DashMap;
+use serde::Serialize;
+
+use crate::cracker::Observer;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+
+/// System allocator keeping track of live/peak bytes and #allocations.
+/// Counting costs a few atomics per allocation, so binaries only install it with the `memory` feature:
+/// ```ignore
+/// #[global_allocator]
+/// static GLOBAL: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    #[inline]
+    fn record_alloc(size: usize) {
+        INSTALLED.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Counters of the [`CountingAllocator`], all zeros when it is not installed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Live bytes
+    pub current: usize,
+    /// Max live bytes since the last [`reset_peak`]
+    pub peak: usize,
+    pub allocations: u64,
+    /// Bytes ever allocated
+    pub allocated: u64,
+}
+
+/// Whether the counting allocator is the global allocator
+pub fn is_counting() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+pub fn alloc_stats() -> AllocStats {
+    AllocStats {
+        current: CURRENT.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated: ALLOCATED.load(Ordering::Relaxed),
+    }
+}
+
+/// Start a new peak window from the current live bytes
+pub fn reset_peak() {
+    PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+/// Read a `kB` field of /proc/self/status, None outside Linux
+fn proc_status(field: &str) -> Option<usize> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with(field))?;
+    let kb: usize = line[field.len()..].trim().trim_end_matches("kB").trim().parse().ok()?;
+
+    Some(kb * 1024)
+}
+
+/// Resident set size in bytes
+pub fn rss_bytes() -> Option<usize> {
+    proc_status("VmRSS:")
+}
+
+/// Peak resident set size of the process in bytes
+pub fn peak_rss_bytes() -> Option<usize> {
+    proc_status("VmHWM:")
+}
+
+/// Memory used by a phase of the run
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PhaseMemory {
+    /// `load`, `min_selection_{it}` (H_it), `pruning_{it}` (G_it+1), `seed_propagation`
+    pub phase: String,
+    /// Live heap bytes at the end of the phase
+    pub live_bytes: usize,
+    /// Max live heap bytes during the phase
+    pub peak_bytes: usize,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Only sampled by [`MemoryProfile::phase`]: reading /proc would put file I/O inside the timed iterations
+    pub rss_bytes: Option<usize>,
+}
+
+/// Observer splitting the run into phases; every hook closes the phase started by the previous one
+#[derive(Clone, Debug)]
+pub struct MemoryProfile {
+    pub phases: Vec<PhaseMemory>,
+    last: AllocStats,
+}
+
+impl MemoryProfile {
+    /// Open the first phase
+    pub fn start() -> Self {
+        reset_peak();
+        MemoryProfile { phases: Vec::new(), last: alloc_stats() }
+    }
+
+    /// Close the current phase, with the RSS, and open the next one
+    pub fn phase(&mut self, name: impl Into<String>) {
+        self.close(name.into(), rss_bytes());
+    }
+
+    /// Close the current phase from the atomic heap counters only
+    fn close(&mut self, name: String, rss_bytes: Option<usize>) {
+        let now = alloc_stats();
+
+        self.phases.push(PhaseMemory {
+            phase: name,
+            live_bytes: now.current,
+            peak_bytes: now.peak,
+            allocations: now.allocations - self.last.allocations,
+            allocated_bytes: now.allocated - self.last.allocated,
+            rss_bytes,
+        });
+
+        reset_peak();
+        self.last = alloc_stats();
+    }
+
+    pub fn peak_bytes(&self) -> usize {
+        self.phases.iter().map(|p| p.peak_bytes).max().unwrap_or(0)
+    }
+
+    pub fn allocations(&self) -> u64 {
+        self.phases.iter().map(|p| p.allocations).sum()
+    }
+
+    /// Phases whose name starts with `prefix` (e.g. every `min_selection_{it}`)
+    pub fn matching<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a PhaseMemory> {
+        self.phases.iter().filter(move |p| p.phase.starts_with(prefix))
+    }
+}
+
+impl<G, V: NodeTrait> Observer<G, V> for MemoryProfile {
+    fn min_selection(&mut self, it: usize, _gt: &G, _h: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        self.close(format!("min_selection_{it}"), None);
+    }
+
+    fn pruning(&mut self, it: usize, _next: &G, _tree: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        self.close(format!("pruning_{it}"), None);
+    }
+
+    fn seed_propagation(&mut self, _seeds: &DashMap<V, V>, _elapsed: Duration) {
+        self.phase("seed_propagation");
+    }
+}
+
+/// Content of the `--memory` file
+#[derive(Clone, Debug, Serialize)]
+pub struct MemoryReport<'a> {
+    pub file: &'a str,
+    pub variant: &'a str,
+    /// false => built without the `memory` feature, only RSS is available
+    pub counting: bool,
+    pub peak_bytes: usize,
+    pub allocations: u64,
+    pub peak_rss_bytes: Option<usize>,
+    pub phases: &'a [PhaseMemory],
+}
+
+pub fn write_memory(filename: &str, report: &MemoryReport) -> Result<(), Error> {
+    let file = File::create(filename)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), report).map_err(Error::from)
+}
@@ -0,0 +1,96 @@
+use concurrent_graph::ConcurrentWeightedUnGraph;
+use getopts::Options;
+use graph_example::boruvka::{boruvka, write_forest, write_trees};
+use graph_example::logging;
+use io_util::prelude::{read_weighted_from_file, GraphFormat};
+
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+    time::Instant,
+};
+
+use rayon::ThreadPoolBuilder;
+
+fn main() {
+    type V = u32;
+
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("f", "file", "provide the real/integer MatrixMarket file containg the weighted graph", "FILEPATH");
+    opts.optopt("o", "output", "write the forest edges with their weights, mtx for .mtx files, edge list otherwise", "FILEPATH");
+    opts.optopt("", "trees", "write a `seed,nodes,edges,weight` csv row per component (- = stdout)", "FILEPATH");
+    opts.optopt("n", "num_thread", "provide the number of threads to use", "0");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -f FILE [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    //handle -n/--num_threads
+    let num_threads = match matches.opt_str("n") {
+        None => 0,  //let rayon decide
+        Some(v) => v.parse().unwrap(),
+    };
+
+    //setup parallelism
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build_global()
+        .unwrap();
+
+    let Some(filename) = matches.opt_str("f") else {
+        eprintln!("Please provide a filename");
+        return;
+    };
+
+    let edges = match read_weighted_from_file::<V, f64>(&filename) {
+        Ok(edges) => edges,
+        Err(e) => {
+            eprintln!("Error reading edges from file: {e}");
+            return;
+        }
+    };
+    let graph = ConcurrentWeightedUnGraph::from_edges(&edges);
+
+    let now = Instant::now();
+    let forest = boruvka(&graph);
+    println!("{}", now.elapsed().as_millis());
+
+    if let Some(output) = matches.opt_str("o") {
+        let format = GraphFormat::from_path(&output);
+        if let Err(e) = File::create(&output).and_then(|f| write_forest(BufWriter::new(f), &forest.edges, graph.node_count(), format)) {
+            eprintln!("Error writing the forest: {e}");
+        }
+    }
+
+    if let Some(trees_file) = matches.opt_str("trees") {
+        let written = match trees_file.as_str() {
+            "-" => write_trees(io::stdout().lock(), &forest.trees),
+            _ => File::create(&trees_file).and_then(|f| write_trees(BufWriter::new(f), &forest.trees)),
+        };
+
+        if let Err(e) = written {
+            eprintln!("Error writing trees: {e}");
+        }
+    }
+
+    eprintln!("{filename}: {} trees, {} edges, total weight {}", forest.trees.len(), forest.edges.len(), forest.weight());
+}
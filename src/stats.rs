@@ -0,0 +1,73 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Error},
+    time::Duration,
+};
+
+use concurrent_graph::{ConcurrentDiGraph, GraphTrait, NodeTrait};
+use serde::Serialize;
+
+use crate::cracker::Observer;
+
+/// State of the algorithm at the end of an iteration
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct IterationStats {
+    pub iteration: usize,
+    /// Nodes of G_t
+    pub active_nodes: usize,
+    /// Adjacency entries of G_t (an undirected edge is counted twice)
+    pub gt_edges: usize,
+    pub h_edges: usize,
+    /// Nodes that got a parent in T (seeds leave G_t without one)
+    pub deactivated: usize,
+    pub tree_nodes: usize,
+    pub tree_edges: usize,
+    pub min_selection_ms: f64,
+    pub pruning_ms: f64,
+}
+
+/// Observer recording an [`IterationStats`] per iteration
+#[derive(Clone, Debug, Default)]
+pub struct StatsCollector {
+    pub iterations: Vec<IterationStats>,
+    current: IterationStats,
+}
+
+impl<G: GraphTrait<V>, V: NodeTrait> Observer<G, V> for StatsCollector {
+    fn min_selection(&mut self, it: usize, gt: &G, h: &ConcurrentDiGraph<V>, elapsed: Duration) {
+        self.current = IterationStats {
+            iteration: it,
+            active_nodes: gt.node_count(),
+            gt_edges: gt.edge_count(),
+            h_edges: h.edge_count(),
+            min_selection_ms: elapsed.as_secs_f64() * 1000.0,
+            ..Default::default()
+        };
+    }
+
+    fn pruning(&mut self, _it: usize, _next: &G, tree: &ConcurrentDiGraph<V>, elapsed: Duration) {
+        let previous_tree_edges = self.iterations.last().map_or(0, |s| s.tree_edges);
+
+        let mut stats = std::mem::take(&mut self.current);
+        stats.tree_nodes = tree.node_count();
+        stats.tree_edges = tree.edge_count();
+        stats.deactivated = stats.tree_edges - previous_tree_edges;
+        stats.pruning_ms = elapsed.as_secs_f64() * 1000.0;
+
+        self.iterations.push(stats);
+    }
+}
+
+/// Content of the `--stats` file
+#[derive(Clone, Debug, Serialize)]
+pub struct StatsReport<'a> {
+    pub file: &'a str,
+    pub variant: &'a str,
+    pub num_threads: usize,
+    pub iterations: &'a [IterationStats],
+}
+
+pub fn write_stats(filename: &str, report: &StatsReport) -> Result<(), Error> {
+    let file = File::create(filename)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), report).map_err(Error::from)
+}
@@ -0,0 +1,149 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufWriter, Error, Write},
+    path::{Path, PathBuf},
+};
+
+use concurrent_graph::{GraphTrait, NodeTrait};
+use dashmap::DashMap;
+use io_util::prelude::{write_to_file, GraphFormat};
+use rayon::prelude::*;
+
+use crate::cracker::InputGraph;
+
+/// Induced subgraph of a connected component
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subgraph<V> {
+    pub seed: V,
+    /// Sorted
+    pub nodes: Vec<V>,
+    /// Each edge once as (a, b) with a <= b, sorted
+    pub edges: Vec<(V, V)>,
+}
+
+impl<V: NodeTrait> Subgraph<V> {
+    /// Edges with the nodes relabelled 1..n following the order of `nodes`
+    pub fn relabelled(&self) -> Vec<(usize, usize)> {
+        let id = |v: &V| self.nodes.binary_search(v).unwrap() + 1;
+        self.edges.iter().map(|(a, b)| (id(a), id(b))).collect()
+    }
+}
+
+/// Seeds of the components with at least `min_size` nodes, largest first (ties by seed), at most `top` of them
+pub fn select<V: NodeTrait>(sizes: &DashMap<V, usize>, min_size: usize, top: Option<usize>) -> Vec<(V, usize)> {
+    let mut selected: Vec<(V, usize)> = sizes
+        .iter()
+        .map(|entry| (*entry.key(), *entry.value()))
+        .filter(|&(_, size)| size >= min_size)
+        .collect();
+    selected.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    selected.truncate(top.unwrap_or(usize::MAX));
+
+    selected
+}
+
+/// Partition the edges of `graph` among the components of `selected`, in the same order; the others are skipped
+pub fn extract<V: NodeTrait>(graph: &InputGraph<V>, seeds: &DashMap<V, V>, selected: &[V]) -> Vec<Subgraph<V>> {
+    match graph {
+        InputGraph::Undirected(g) => partition(g, seeds, selected),
+        InputGraph::Directed(g) => partition(g, seeds, selected),
+    }
+}
+
+fn partition<G, V>(graph: &G, seeds: &DashMap<V, V>, selected: &[V]) -> Vec<Subgraph<V>>
+where
+    G: GraphTrait<V> + Sync,
+    V: NodeTrait,
+{
+    let index: HashMap<V, usize> = selected.iter().enumerate().map(|(i, &seed)| (seed, i)).collect();
+    let mut subgraphs: Vec<Subgraph<V>> =
+        selected.iter().map(|&seed| Subgraph { seed, nodes: Vec::new(), edges: Vec::new() }).collect();
+
+    for entry in seeds.iter() {
+        if let Some(&i) = index.get(entry.value()) {
+            subgraphs[i].nodes.push(*entry.key());
+        }
+    }
+
+    subgraphs.par_iter_mut().for_each(|subgraph| {
+        subgraph.nodes.sort_unstable();
+
+        // both graphs store every edge in both directions
+        for &a in &subgraph.nodes {
+            let mut neighbors: Vec<V> = graph.outgoing_edges(a).into_iter().filter(|&b| a <= b).collect();
+            neighbors.sort_unstable();
+            subgraph.edges.extend(neighbors.into_iter().map(|b| (a, b)));
+        }
+    });
+
+    subgraphs
+}
+
+/// Induced subgraph of the largest component, None on an empty graph
+pub fn giant<V: NodeTrait>(graph: &InputGraph<V>, seeds: &DashMap<V, V>, sizes: &DashMap<V, usize>) -> Option<Subgraph<V>> {
+    let (seed, _) = select(sizes, 1, Some(1)).pop()?;
+    extract(graph, seeds, &[seed]).pop()
+}
+
+fn extension(format: GraphFormat) -> &'static str {
+    match format {
+        GraphFormat::MatrixMarket => "mtx",
+        GraphFormat::EdgeList => "el",
+    }
+}
+
+/// `{dir}/component_{rank}.{mtx|el}`, rank 0 being the largest component
+pub fn component_path(dir: &Path, rank: usize, format: GraphFormat) -> PathBuf {
+    dir.join(format!("component_{rank}.{}", extension(format)))
+}
+
+/// Write a component: MatrixMarket files are relabelled 1..n, edge lists keep the original ids
+pub fn write_subgraph<V>(filename: &Path, subgraph: &Subgraph<V>, format: GraphFormat) -> Result<(), Error>
+where V: NodeTrait + std::fmt::Display,
+{
+    let filename = filename.display().to_string();
+    match format {
+        GraphFormat::MatrixMarket => write_to_file(&filename, &subgraph.relabelled(), subgraph.nodes.len(), format),
+        GraphFormat::EdgeList => write_to_file(&filename, &subgraph.edges, subgraph.nodes.len(), format),
+    }
+}
+
+/// One original id per line: line i is node i of the relabelled MatrixMarket file
+pub fn write_nodes<V: std::fmt::Display>(filename: &Path, nodes: &[V]) -> Result<(), Error> {
+    let mut out = BufWriter::new(File::create(filename)?);
+    for v in nodes {
+        writeln!(out, "{v}")?;
+    }
+
+    out.flush()
+}
+
+/// Write the giant component as `{dir}/giant.{mtx|el}` and its node list as `{dir}/giant.nodes`
+pub fn write_giant<V>(dir: &Path, giant: &Subgraph<V>, format: GraphFormat) -> Result<(), Error>
+where V: NodeTrait + std::fmt::Display,
+{
+    fs::create_dir_all(dir)?;
+    write_subgraph(&dir.join(format!("giant.{}", extension(format))), giant, format)?;
+    write_nodes(&dir.join("giant.nodes"), &giant.nodes)
+}
+
+/// Write every subgraph in `dir` (created if missing) plus an `index.csv` of file,seed,nodes,edges
+pub fn write_components<V>(dir: &Path, subgraphs: &[Subgraph<V>], format: GraphFormat) -> Result<(), Error>
+where V: NodeTrait + std::fmt::Display,
+{
+    fs::create_dir_all(dir)?;
+
+    let mut index = BufWriter::new(File::create(dir.join("index.csv"))?);
+    writeln!(index, "file,seed,nodes,edges")?;
+
+    for (rank, subgraph) in subgraphs.iter().enumerate() {
+        let path = component_path(dir, rank, format);
+        write_subgraph(&path, subgraph, format)?;
+
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        writeln!(index, "{},{},{},{}", name, subgraph.seed, subgraph.nodes.len(), subgraph.edges.len())?;
+    }
+
+    index.flush()
+}
@@ -0,0 +1,129 @@
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::{BufWriter, Error, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use concurrent_graph::{ConcurrentDiGraph, ConcurrentUnGraph, GraphTrait, NodeTrait};
+use io_util::prelude::{write_to_file, GraphFormat};
+use rayon::prelude::*;
+
+use crate::cracker::Observer;
+
+/// Sorted edges of a graph, as written in the trace
+pub trait Arcs<V> {
+    fn arcs(&self) -> Vec<(V, V)>;
+}
+
+impl<V: NodeTrait> Arcs<V> for ConcurrentUnGraph<V> {
+    /// Each edge once, as (a, b) with a <= b
+    fn arcs(&self) -> Vec<(V, V)> {
+        let mut arcs: Vec<(V, V)> = self
+            .nodes()
+            .into_par_iter()
+            .flat_map_iter(|a| self.outgoing_edges(a).into_iter().filter(move |&b| a <= b).map(move |b| (a, b)))
+            .collect();
+        arcs.par_sort_unstable();
+        arcs
+    }
+}
+
+impl<V: NodeTrait> Arcs<V> for ConcurrentDiGraph<V> {
+    /// Every a -> b
+    fn arcs(&self) -> Vec<(V, V)> {
+        let mut arcs: Vec<(V, V)> = self
+            .nodes()
+            .into_par_iter()
+            .flat_map_iter(|a| self.outgoing_edges(a).into_iter().map(move |b| (a, b)))
+            .collect();
+        arcs.par_sort_unstable();
+        arcs
+    }
+}
+
+/// Sizes of the graphs of an iteration, one line of `summary.csv`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TraceRow {
+    pub iteration: usize,
+    pub gt_nodes: usize,
+    pub gt_edges: usize,
+    pub h_edges: usize,
+    pub next_nodes: usize,
+    pub tree_edges: usize,
+}
+
+/// Observer writing `g_{it}.el`, `h_{it}.el` and the cumulative `t_{it}.el` of every iteration in a directory,
+/// plus `summary.csv` when finished
+#[derive(Debug)]
+pub struct TraceWriter {
+    dir: PathBuf,
+    pub rows: Vec<TraceRow>,
+    /// First failed write: hooks cannot fail, [`TraceWriter::finish`] reports it
+    error: Option<Error>,
+}
+
+impl TraceWriter {
+    /// Create `dir` if missing
+    pub fn new(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        fs::create_dir_all(&dir)?;
+        Ok(TraceWriter { dir: dir.as_ref().to_path_buf(), rows: Vec::new(), error: None })
+    }
+
+    fn write<V: Display>(&mut self, name: String, arcs: &[(V, V)]) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let path = self.dir.join(name).display().to_string();
+        if let Err(e) = write_to_file(&path, arcs, 0, GraphFormat::EdgeList) {
+            self.error = Some(e);
+        }
+    }
+
+    /// Write `summary.csv`, or return the first error met while tracing
+    pub fn finish(self) -> Result<(), Error> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        let mut out = BufWriter::new(File::create(self.dir.join("summary.csv"))?);
+        writeln!(out, "iteration,gt_nodes,gt_edges,h_edges,next_nodes,tree_edges")?;
+        for r in &self.rows {
+            writeln!(out, "{},{},{},{},{},{}", r.iteration, r.gt_nodes, r.gt_edges, r.h_edges, r.next_nodes, r.tree_edges)?;
+        }
+
+        out.flush()
+    }
+}
+
+impl<G, V> Observer<G, V> for TraceWriter
+where
+    G: GraphTrait<V> + Arcs<V>,
+    V: NodeTrait + Display,
+{
+    fn min_selection(&mut self, it: usize, gt: &G, h: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        let (gt_arcs, h_arcs) = (gt.arcs(), h.arcs());
+        self.write(format!("g_{it}.el"), &gt_arcs);
+        self.write(format!("h_{it}.el"), &h_arcs);
+
+        self.rows.push(TraceRow {
+            iteration: it,
+            gt_nodes: gt.node_count(),
+            gt_edges: gt_arcs.len(),
+            h_edges: h_arcs.len(),
+            ..Default::default()
+        });
+    }
+
+    fn pruning(&mut self, it: usize, next: &G, tree: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        let tree_arcs = tree.arcs();
+        self.write(format!("t_{it}.el"), &tree_arcs);
+
+        if let Some(row) = self.rows.last_mut() {
+            row.next_nodes = next.node_count();
+            row.tree_edges = tree_arcs.len();
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Error, Write},
+    path::Path,
+};
+
+use concurrent_graph::{ConcurrentDiGraph, GraphTrait, NodeTrait};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::cracker::Cracker;
+
+/// (node, parent in T) of every node of T, sorted by node; roots are their own parent
+pub fn parent_array<V: NodeTrait>(tree: &ConcurrentDiGraph<V>) -> Vec<(V, V)> {
+    let mut parents: Vec<(V, V)> = tree
+        .nodes()
+        .into_par_iter()
+        .map(|v| (v, tree.incoming_edges(v).into_iter().next().unwrap_or(v)))
+        .collect();
+    parents.par_sort_unstable();
+
+    parents
+}
+
+/// One `node parent` line per node
+pub fn write_parents<W, V>(mut out: W, parents: &[(V, V)]) -> Result<(), Error>
+where
+    W: Write,
+    V: fmt::Display,
+{
+    for (v, p) in parents {
+        writeln!(out, "{v} {p}")?;
+    }
+
+    out.flush()
+}
+
+/// Graphviz digraph of T, parent -> child, roots drawn as double circles
+pub fn write_dot<W, V>(mut out: W, parents: &[(V, V)]) -> Result<(), Error>
+where
+    W: Write,
+    V: NodeTrait + fmt::Display,
+{
+    writeln!(out, "digraph T {{")?;
+    for (v, _) in parents.iter().filter(|(v, p)| v == p) {
+        writeln!(out, "  {v} [shape=doublecircle];")?;
+    }
+    for (v, p) in parents.iter().filter(|(v, p)| v != p) {
+        writeln!(out, "  {p} -> {v};")?;
+    }
+    writeln!(out, "}}")?;
+
+    out.flush()
+}
+
+/// Shape of the propagation tree T
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TreeStats {
+    pub nodes: usize,
+    /// One per component
+    pub roots: usize,
+    pub leaves: usize,
+    /// Longest root-to-node path, in edges
+    pub depth: usize,
+    pub mean_depth: f64,
+    /// #children of the internal nodes
+    pub max_fan_out: usize,
+    pub mean_fan_out: f64,
+    /// (depth, #nodes at that depth)
+    pub depths: Vec<(usize, usize)>,
+    /// (#children, #nodes with that many children), leaves included
+    pub fan_out: Vec<(usize, usize)>,
+}
+
+impl TreeStats {
+    pub fn new<V: NodeTrait>(res: &Cracker<V>) -> Self {
+        let parents = parent_array(&res.tree);
+        let node_depths = res.node_depths();
+
+        let mut depths: BTreeMap<usize, usize> = BTreeMap::new();
+        for d in node_depths.values() {
+            *depths.entry(*d).or_insert(0) += 1;
+        }
+
+        let mut fan_out: BTreeMap<usize, usize> = BTreeMap::new();
+        for v in res.tree.nodes() {
+            *fan_out.entry(res.tree.outgoing_edges(v).len()).or_insert(0) += 1;
+        }
+
+        let nodes = parents.len();
+        let roots = parents.iter().filter(|(v, p)| v == p).count();
+        let leaves = fan_out.get(&0).copied().unwrap_or(0);
+        let internal = nodes - leaves;
+
+        TreeStats {
+            nodes,
+            roots,
+            leaves,
+            depth: depths.keys().last().copied().unwrap_or(0),
+            mean_depth: if nodes > 0 { node_depths.values().sum::<usize>() as f64 / nodes as f64 } else { 0.0 },
+            max_fan_out: fan_out.keys().last().copied().unwrap_or(0),
+            // every non-root node is the child of an internal node
+            mean_fan_out: if internal > 0 { (nodes - roots) as f64 / internal as f64 } else { 0.0 },
+            depths: depths.into_iter().collect(),
+            fan_out: fan_out.into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for TreeStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "nodes: {}", self.nodes)?;
+        writeln!(f, "roots: {}", self.roots)?;
+        writeln!(f, "leaves: {}", self.leaves)?;
+        writeln!(f, "depth: {} (mean {:.3})", self.depth, self.mean_depth)?;
+        writeln!(f, "fan-out: max {} (mean over internal nodes {:.3})", self.max_fan_out, self.mean_fan_out)?;
+
+        writeln!(f, "nodes per depth:")?;
+        for (depth, count) in &self.depths {
+            writeln!(f, "  {depth}: {count}")?;
+        }
+        writeln!(f, "nodes per fan-out:")?;
+        for (children, count) in &self.fan_out {
+            writeln!(f, "  {children}: {count}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Content of the `--tree_stats` file
+#[derive(Clone, Debug, Serialize)]
+pub struct TreeReport<'a> {
+    pub file: &'a str,
+    pub variant: &'a str,
+    pub iterations: usize,
+    #[serde(flatten)]
+    pub stats: &'a TreeStats,
+}
+
+/// Write the report to `filename` (`-` for stdout): JSON for `.json` files, human-readable otherwise
+pub fn write_tree_stats(filename: &str, report: &TreeReport) -> Result<(), Error> {
+    let mut out: Box<dyn Write> = match filename {
+        "-" => Box::new(io::stdout()),
+        _ => Box::new(BufWriter::new(File::create(filename)?)),
+    };
+
+    if Path::new(filename).extension().is_some_and(|ext| ext == "json") {
+        serde_json::to_writer_pretty(out, report).map_err(Error::from)
+    } else {
+        writeln!(out, "file: {}", report.file)?;
+        writeln!(out, "variant: {}", report.variant)?;
+        writeln!(out, "iterations: {}", report.iterations)?;
+        write!(out, "{}", report.stats)?;
+        out.flush()
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    collections::{HashMap, HashSet},
+    f64::consts::PI,
+    fmt::{Display, Write},
+    time::Duration,
+};
+
+use concurrent_graph::{ConcurrentDiGraph, GraphTrait, NodeTrait};
+use dashmap::DashMap;
+
+use crate::cracker::Observer;
+use crate::trace::Arcs;
+
+/// Graphs of an iteration, as drawn in a frame
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame<V> {
+    pub iteration: usize,
+    /// Nodes of G_t
+    pub active: Vec<V>,
+    pub gt: Vec<(V, V)>,
+    pub h: Vec<(V, V)>,
+    /// G_t+1
+    pub next: Vec<(V, V)>,
+    /// Cumulative T
+    pub tree: Vec<(V, V)>,
+    /// Nodes that got a parent in T in this iteration
+    pub deactivated: Vec<V>,
+}
+
+/// Observer keeping a [`Frame`] per iteration and the final {node: seed}
+#[derive(Clone, Debug)]
+pub struct FrameRecorder<V> {
+    pub frames: Vec<Frame<V>>,
+    pub seeds: Vec<(V, V)>,
+}
+
+impl<V> Default for FrameRecorder<V> {
+    fn default() -> Self {
+        FrameRecorder { frames: Vec::new(), seeds: Vec::new() }
+    }
+}
+
+impl<G, V> Observer<G, V> for FrameRecorder<V>
+where
+    G: GraphTrait<V> + Arcs<V>,
+    V: NodeTrait,
+{
+    fn min_selection(&mut self, it: usize, gt: &G, h: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        let mut active = gt.nodes();
+        active.sort_unstable();
+
+        self.frames.push(Frame {
+            iteration: it,
+            active,
+            gt: gt.arcs(),
+            h: h.arcs(),
+            next: Vec::new(),
+            tree: Vec::new(),
+            deactivated: Vec::new(),
+        });
+    }
+
+    fn pruning(&mut self, _it: usize, next: &G, tree: &ConcurrentDiGraph<V>, _elapsed: Duration) {
+        let before: HashSet<V> = self.frames.iter().rev().nth(1).map_or(HashSet::new(), |f| f.tree.iter().map(|e| e.1).collect());
+
+        if let Some(frame) = self.frames.last_mut() {
+            frame.next = next.arcs();
+            frame.tree = tree.arcs();
+            frame.deactivated = frame.tree.iter().map(|e| e.1).filter(|v| !before.contains(v)).collect();
+            frame.deactivated.sort_unstable();
+        }
+    }
+
+    fn seed_propagation(&mut self, seeds: &DashMap<V, V>, _elapsed: Duration) {
+        self.seeds = seeds.iter().map(|entry| (*entry.key(), *entry.value())).collect();
+        self.seeds.sort_unstable();
+    }
+}
+
+const PANEL: f64 = 300.0;
+const RADIUS: f64 = 13.0;
+const TOP: f64 = 40.0;
+const PALETTE: [&str; 8] = ["#8ecae6", "#ffb703", "#90be6d", "#f28482", "#cdb4db", "#f9c74f", "#84a59d", "#e9c46a"];
+
+/// Nodes on a circle, in id order, so that they keep their place in every panel
+#[derive(Clone, Debug)]
+pub struct Layout<V> {
+    positions: HashMap<V, (f64, f64)>,
+}
+
+impl<V: NodeTrait> Layout<V> {
+    pub fn circle(nodes: &[V]) -> Self {
+        let mut nodes = nodes.to_vec();
+        nodes.sort_unstable();
+        nodes.dedup();
+
+        let r = PANEL / 2.0 - 2.0 * RADIUS;
+        let n = nodes.len().max(1) as f64;
+        let positions = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                let angle = 2.0 * PI * i as f64 / n - PI / 2.0;
+                (v, (PANEL / 2.0 + r * angle.cos(), PANEL / 2.0 + r * angle.sin()))
+            })
+            .collect();
+
+        Layout { positions }
+    }
+
+    fn at(&self, v: &V, panel: usize) -> (f64, f64) {
+        let (x, y) = self.positions[v];
+        (x + panel as f64 * PANEL, y + TOP)
+    }
+}
+
+/// How a panel draws its edges and colors its nodes
+struct Panel<'a, V> {
+    title: &'a str,
+    edges: &'a [(V, V)],
+    directed: bool,
+    fill: &'a dyn Fn(&V) -> &'static str,
+}
+
+fn draw_panel<V: NodeTrait + Display>(svg: &mut String, layout: &Layout<V>, nodes: &[V], index: usize, panel: &Panel<V>) {
+    let x0 = index as f64 * PANEL;
+    let _ = writeln!(svg, r#"<text x="{:.1}" y="24" class="title">{}</text>"#, x0 + PANEL / 2.0, panel.title);
+    let _ = writeln!(svg, r#"<rect x="{x0:.1}" y="{TOP}" width="{PANEL}" height="{PANEL}" class="panel"/>"#);
+
+    let marker = if panel.directed { r#" marker-end="url(#arrow)""# } else { "" };
+    for (a, b) in panel.edges {
+        let (x1, y1) = layout.at(a, index);
+        if a == b {
+            let _ = writeln!(svg, r#"<circle cx="{x1:.1}" cy="{:.1}" r="8" class="loop"/>"#, y1 - RADIUS - 6.0);
+            continue;
+        }
+
+        // stop at the border of the nodes so that arrow heads stay visible
+        let (x2, y2) = layout.at(b, index);
+        let len = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt().max(1.0);
+        let (dx, dy) = ((x2 - x1) / len * RADIUS, (y2 - y1) / len * RADIUS);
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" class="edge"{marker}/>"#,
+            x1 + dx, y1 + dy, x2 - dx, y2 - dy
+        );
+    }
+
+    for v in nodes {
+        let (x, y) = layout.at(v, index);
+        let _ = writeln!(svg, r#"<circle cx="{x:.1}" cy="{y:.1}" r="{RADIUS}" fill="{}" class="node"/>"#, (panel.fill)(v));
+        let _ = writeln!(svg, r#"<text x="{x:.1}" y="{:.1}" class="label">{v}</text>"#, y + 4.0);
+    }
+}
+
+fn svg<V: NodeTrait + Display>(layout: &Layout<V>, nodes: &[V], panels: &[Panel<V>], caption: &str) -> String {
+    let width = PANEL * panels.len() as f64;
+    let height = TOP + PANEL + 30.0;
+
+    let mut svg = String::new();
+    let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#);
+    let _ = writeln!(
+        svg,
+        r##"<defs><marker id="arrow" viewBox="0 0 10 10" refX="9" refY="5" markerWidth="7" markerHeight="7" orient="auto"><path d="M0,0 L10,5 L0,10 z" fill="#555"/></marker></defs>"##
+    );
+    let _ = writeln!(
+        svg,
+        "<style>.title{{font:bold 15px sans-serif;text-anchor:middle}} .caption{{font:13px sans-serif}} .panel{{fill:none;stroke:#ccc}} \
+         .edge{{stroke:#555;stroke-width:1.5}} .loop{{fill:none;stroke:#555}} .node{{stroke:#333}} \
+         .label{{font:11px sans-serif;text-anchor:middle}}</style>"
+    );
+
+    for (i, panel) in panels.iter().enumerate() {
+        draw_panel(&mut svg, layout, nodes, i, panel);
+    }
+
+    let _ = writeln!(svg, r#"<text x="10" y="{:.1}" class="caption">{caption}</text>"#, height - 10.0);
+    let _ = writeln!(svg, "</svg>");
+
+    svg
+}
+
+/// Iteration `frame` as four panels: G_t, H_t, G_t+1 and T.
+/// Nodes of G_t are white, the ones deactivated in this iteration orange, the already inactive ones grey
+pub fn render_frame<V: NodeTrait + Display>(frame: &Frame<V>, layout: &Layout<V>, nodes: &[V]) -> String {
+    let active: HashSet<V> = frame.active.iter().copied().collect();
+    let deactivated: HashSet<V> = frame.deactivated.iter().copied().collect();
+    let fill = |v: &V| match (deactivated.contains(v), active.contains(v)) {
+        (true, _) => "#f4a261",
+        (false, true) => "#ffffff",
+        (false, false) => "#dddddd",
+    };
+
+    let it = frame.iteration;
+    let (gt, h, next, tree) = (format!("G_{it}"), format!("H_{it} (min selection)"), format!("G_{} (pruning)", it + 1), "T".to_string());
+    let panels = [
+        Panel { title: &gt, edges: &frame.gt, directed: false, fill: &fill },
+        Panel { title: &h, edges: &frame.h, directed: true, fill: &fill },
+        Panel { title: &next, edges: &frame.next, directed: false, fill: &fill },
+        Panel { title: &tree, edges: &frame.tree, directed: true, fill: &fill },
+    ];
+
+    let caption = format!(
+        "iteration {it}: {} active nodes, {} deactivated (orange), {} left in G_{}",
+        frame.active.len(),
+        frame.deactivated.len(),
+        frame.next.iter().flat_map(|&(a, b)| [a, b]).collect::<HashSet<V>>().len(),
+        it + 1
+    );
+
+    svg(layout, nodes, &panels, &caption)
+}
+
+/// Final T with the nodes colored by seed
+pub fn render_seeds<V: NodeTrait + Display>(recorder: &FrameRecorder<V>, layout: &Layout<V>, nodes: &[V]) -> String {
+    let mut roots: Vec<V> = recorder.seeds.iter().map(|s| s.1).collect();
+    roots.sort_unstable();
+    roots.dedup();
+
+    let seeds: HashMap<V, V> = recorder.seeds.iter().copied().collect();
+    let fill = |v: &V| match seeds.get(v).and_then(|s| roots.binary_search(s).ok()) {
+        Some(i) => PALETTE[i % PALETTE.len()],
+        None => "#dddddd",
+    };
+
+    let tree = recorder.frames.last().map_or(&[][..], |f| &f.tree[..]);
+    let panels = [Panel { title: "T (seed propagation)", edges: tree, directed: true, fill: &fill }];
+    let caption = format!("{} components", roots.len());
+
+    svg(layout, nodes, &panels, &caption)
+}
+
+/// Self-contained page stepping through the frames (buttons or arrow keys), no external assets
+pub fn render_html<V: NodeTrait + Display>(title: &str, recorder: &FrameRecorder<V>, layout: &Layout<V>, nodes: &[V]) -> String {
+    let mut frames: Vec<String> = recorder.frames.iter().map(|f| render_frame(f, layout, nodes)).collect();
+    frames.push(render_seeds(recorder, layout, nodes));
+
+    let mut html = String::new();
+    let _ = writeln!(html, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>CRACKER on {title}</title>");
+    let _ = writeln!(html, "<style>body{{font-family:sans-serif;margin:20px}} .frame{{display:none}} .frame.shown{{display:block}}</style>\n</head>\n<body>");
+    let _ = writeln!(html, "<h1>CRACKER on {title}</h1>");
+    let _ = writeln!(html, "<p><button id=\"prev\">&larr;</button> <span id=\"step\"></span> <button id=\"next\">&rarr;</button></p>");
+    for frame in &frames {
+        let _ = writeln!(html, "<div class=\"frame\">\n{frame}</div>");
+    }
+    let _ = writeln!(
+        html,
+        "<script>\n\
+         const frames = document.querySelectorAll('.frame');\n\
+         let current = 0;\n\
+         function show(i) {{\n\
+         \x20 current = Math.max(0, Math.min(frames.length - 1, i));\n\
+         \x20 frames.forEach((f, j) => f.classList.toggle('shown', j === current));\n\
+         \x20 document.getElementById('step').textContent = (current + 1) + ' / ' + frames.length;\n\
+         }}\n\
+         document.getElementById('prev').onclick = () => show(current - 1);\n\
+         document.getElementById('next').onclick = () => show(current + 1);\n\
+         document.addEventListener('keydown', e => {{ if (e.key === 'ArrowLeft') show(current - 1); if (e.key === 'ArrowRight') show(current + 1); }});\n\
+         show(0);\n\
+         </script>\n</body>\n</html>"
+    );
+
+    html
+}
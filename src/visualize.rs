@@ -0,0 +1,113 @@
+use getopts::Options;
+use graph_example::cracker::Variant;
+use graph_example::logging;
+use graph_example::visual::{render_frame, render_html, render_seeds, FrameRecorder, Layout};
+use io_util::prelude::read_from_file;
+
+use std::{fs, path::Path};
+
+fn main() {
+    type V = u32;
+
+    //get cli args
+    let args: Vec<String> = std::env::args().collect();
+
+    //get opts
+    let mut opts = Options::new();
+    opts.optopt("f", "file", "provide the file containg the graph", "FILEPATH");
+    opts.optopt("", "html", "write a self-contained html page stepping through the iterations", "FILEPATH");
+    opts.optopt("", "svg", "write one svg frame per iteration (frame_<it>.svg) plus seeds.svg", "DIR");
+    opts.optopt("", "variant", "variant to visualize (default ep)", "base|ep|epos");
+    opts.optopt("", "max_nodes", "refuse larger graphs, the drawings are unreadable beyond a few dozen nodes (default 100)", "N");
+    opts.optflagmulti("v", "verbose", "log to stderr (-v info, -vv debug, -vvv trace), RUST_LOG overrides it");
+    opts.optflag("h", "help", "print help menu");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(matches) => matches,
+        Err(fail) => {
+            panic!("{}", fail.to_string())
+        }
+    };
+
+    //handle -h/--help
+    if matches.opt_present("h") {
+        let brief = format!("Usage: {} -f FILE [--html FILE] [--svg DIR] [options]", args[0]);
+        print!("{}", opts.usage(&brief));
+
+        return;
+    }
+
+    logging::init(matches.opt_count("v"));
+
+    let Some(filename) = matches.opt_str("f") else {
+        eprintln!("Please provide a filename");
+        return;
+    };
+    let (html, svg) = (matches.opt_str("html"), matches.opt_str("svg"));
+    if html.is_none() && svg.is_none() {
+        eprintln!("Please provide --html and/or --svg");
+        return;
+    }
+
+    let variant = match matches.opt_str("variant").map(|v| v.parse::<Variant>()) {
+        None => Variant::Ep,
+        Some(Ok(variant)) => variant,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+    let max_nodes: usize = match matches.opt_str("max_nodes").map(|v| v.parse()) {
+        None => 100,
+        Some(Ok(max_nodes)) => max_nodes,
+        Some(Err(_)) => {
+            eprintln!("Invalid max_nodes");
+            return;
+        }
+    };
+
+    let edges = match read_from_file::<V>(&filename) {
+        Ok(edges) => edges,
+        Err(e) => {
+            eprintln!("Error reading edges from file: {e}");
+            return;
+        }
+    };
+
+    let mut nodes: Vec<V> = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+    nodes.sort_unstable();
+    nodes.dedup();
+    if nodes.len() > max_nodes {
+        eprintln!("{filename} has {} nodes, more than --max_nodes {max_nodes}", nodes.len());
+        return;
+    }
+
+    let mut recorder = FrameRecorder::default();
+    variant.crack_observed(&variant.graph(&edges), &mut recorder);
+    let layout = Layout::circle(&nodes);
+
+    if let Some(html) = html {
+        let title = Path::new(&filename).file_name().map_or(filename.clone(), |f| f.to_string_lossy().into_owned());
+        if let Err(e) = fs::write(&html, render_html(&title, &recorder, &layout, &nodes)) {
+            eprintln!("Error writing {html}: {e}");
+            return;
+        }
+    }
+
+    if let Some(dir) = svg {
+        let dir = Path::new(&dir);
+        let written = fs::create_dir_all(dir).and_then(|_| {
+            for frame in &recorder.frames {
+                fs::write(dir.join(format!("frame_{}.svg", frame.iteration)), render_frame(frame, &layout, &nodes))?;
+            }
+            fs::write(dir.join("seeds.svg"), render_seeds(&recorder, &layout, &nodes))
+        });
+
+        if let Err(e) = written {
+            eprintln!("Error writing frames to {}: {e}", dir.display());
+            return;
+        }
+    }
+
+    eprintln!("{filename}: {} iterations, {} nodes", recorder.frames.len(), nodes.len());
+}
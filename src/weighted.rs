@@ -0,0 +1,28 @@
+use concurrent_graph::NodeTrait;
+use rayon::prelude::*;
+
+/// (a, b, weight) as read from a `real`/`integer` MatrixMarket file
+pub type WeightedEdge<V> = (V, V, f64);
+
+/// Edges whose weight satisfies `keep`, in input order
+pub fn filter_edges<V, P>(edges: &[WeightedEdge<V>], keep: P) -> Vec<(V, V)>
+where
+    V: NodeTrait,
+    P: Fn(f64) -> bool + Sync,
+{
+    edges.par_iter().filter(|e| keep(e.2)).map(|&(a, b, _)| (a, b)).collect()
+}
+
+/// Edges with weight ≥ `tau`; nodes left without edges are still singletons, build the graph with [`nodes`] to keep them
+pub fn min_weight<V: NodeTrait>(edges: &[WeightedEdge<V>], tau: f64) -> Vec<(V, V)> {
+    filter_edges(edges, |w| w >= tau)
+}
+
+/// Endpoints of the weighted edges, sorted: the node set, whatever the threshold
+pub fn nodes<V: NodeTrait>(edges: &[WeightedEdge<V>]) -> Vec<V> {
+    let mut nodes: Vec<V> = edges.par_iter().flat_map_iter(|&(a, b, _)| [a, b]).collect();
+    nodes.par_sort_unstable();
+    nodes.dedup();
+
+    nodes
+}
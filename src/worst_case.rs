@@ -0,0 +1,105 @@
+use std::{
+    io::{self, Error, Write},
+    str::FromStr,
+};
+
+use serde::Serialize;
+
+use crate::cracker::Variant;
+use crate::generators::Generator;
+
+/// Families whose labelling is adversarial for min-label propagation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Family {
+    /// 0 - 1 - ... - (n-1)
+    IncreasingPath,
+    /// 0 - (n-1) - 1 - (n-2) - ...
+    ZigzagPath,
+    /// increasing spine, 2 leaves per spine node
+    Caterpillar,
+    /// increasing spine of ~sqrt(n) nodes, each with a hanging path
+    Comb,
+}
+
+impl Family {
+    pub const ALL: [Family; 4] = [Family::IncreasingPath, Family::ZigzagPath, Family::Caterpillar, Family::Comb];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Family::IncreasingPath => "path",
+            Family::ZigzagPath => "zigzag",
+            Family::Caterpillar => "caterpillar",
+            Family::Comb => "comb",
+        }
+    }
+
+    /// Member of the family with (about) `n` nodes
+    pub fn generator(&self, n: usize) -> Generator {
+        match self {
+            Family::IncreasingPath => Generator::Path { nodes: n },
+            Family::ZigzagPath => Generator::ZigzagPath { nodes: n },
+            Family::Caterpillar => Generator::Caterpillar { spine: (n / 3).max(1), legs: 2 },
+            Family::Comb => {
+                let teeth = ((n as f64).sqrt().round() as usize).max(1);
+                Generator::Comb { teeth, length: (n / teeth).saturating_sub(1) }
+            }
+        }
+    }
+}
+
+impl FromStr for Family {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Family::ALL
+            .into_iter()
+            .find(|family| family.name() == s)
+            .ok_or(format!("unknown family {s:?} (expected path, zigzag, caterpillar or comb)"))
+    }
+}
+
+/// Convergence of a variant on one member of a family
+#[derive(Clone, Debug, Serialize)]
+pub struct WorstCase {
+    pub family: &'static str,
+    pub nodes: usize,
+    pub edges: usize,
+    pub variant: &'static str,
+    pub iterations: usize,
+    pub tree_depth: usize,
+    /// Reference for the logarithmic bound of the CRACKER paper
+    pub log2_nodes: f64,
+}
+
+pub fn worst_case(family: Family, n: usize, variant: Variant) -> Result<WorstCase, String> {
+    let graph = family.generator(n).generate(0)?;
+    let res = variant.run(&graph.edges);
+
+    Ok(WorstCase {
+        family: family.name(),
+        nodes: graph.num_nodes,
+        edges: graph.edges.len(),
+        variant: variant.name(),
+        iterations: res.iterations,
+        tree_depth: res.tree_depth(),
+        log2_nodes: (graph.num_nodes.max(1) as f64).log2(),
+    })
+}
+
+pub fn write_csv<W: Write>(mut out: W, rows: &[WorstCase]) -> io::Result<()> {
+    writeln!(out, "family,nodes,edges,variant,iterations,tree_depth,log2_nodes")?;
+
+    for r in rows {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{:.3}",
+            r.family, r.nodes, r.edges, r.variant, r.iterations, r.tree_depth, r.log2_nodes
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn write_json<W: Write>(out: W, rows: &[WorstCase]) -> io::Result<()> {
+    serde_json::to_writer_pretty(out, rows).map_err(Error::from)
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use graph_example::benchmark::{
+        bench_file, parse_list, scaling, thread_sweep, write_csv, BenchConfig, Measurement, Summary, PHASES,
+    };
+    use graph_example::cracker::Variant;
+
+    #[test]
+    fn summary_test() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let s = Summary::new(&samples);
+
+        assert_eq!(s.median_ms, 2.5);
+        assert_eq!(s.mean_ms, 2.5);
+        assert_eq!(s.min_ms, 1.0);
+        assert_eq!(s.max_ms, 4.0);
+        assert!((s.stddev_ms - (5.0f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        assert_eq!(Summary::new(&samples[..1]).stddev_ms, 0.0);
+    }
+
+    #[test]
+    fn parse_list_test() {
+        assert_eq!(parse_list::<usize>("1,2, 4").unwrap(), vec![1, 2, 4]);
+        assert_eq!(parse_list::<Variant>("base,epos").unwrap(), vec![Variant::Base, Variant::EpOs]);
+        assert!(parse_list::<Variant>("base,naive").is_err());
+    }
+
+    #[test]
+    fn bench_example() {
+        let file = concat!(env!("CARGO_MANIFEST_DIR"), "/files/example.mtx");
+        let config = BenchConfig { num_threads: vec![1, 2], warmup: 0, runs: 2, ..Default::default() };
+
+        let measurements = bench_file(file, &config).unwrap();
+        assert_eq!(measurements.len(), 2 * Variant::ALL.len() * PHASES.len());
+        assert!(measurements.iter().all(|m| m.nodes == 11 && m.edges == 10 && m.runs == 2));
+
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &measurements).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap().lines().count(), measurements.len() + 1);
+    }
+
+    #[test]
+    fn thread_sweep_test() {
+        assert_eq!(thread_sweep(1), vec![1]);
+        assert_eq!(thread_sweep(8), vec![1, 2, 4, 8]);
+        assert_eq!(thread_sweep(6), vec![1, 2, 4, 6]);
+    }
+
+    #[test]
+    fn scaling_test() {
+        let measurement = |num_threads: usize, ms: u64| Measurement {
+            file: "g.mtx".to_string(),
+            nodes: 10,
+            edges: 20,
+            variant: "ep",
+            num_threads,
+            phase: "total",
+            runs: 1,
+            summary: Summary::new(&[Duration::from_millis(ms)]),
+            peak_bytes: None,
+            allocations: None,
+        };
+
+        let res = scaling(&[measurement(1, 100), measurement(2, 50), measurement(4, 40)]);
+
+        let speedups: Vec<f64> = res.iter().map(|s| s.speedup).collect();
+        let efficiencies: Vec<f64> = res.iter().map(|s| s.efficiency).collect();
+        assert_eq!(speedups, vec![1.0, 2.0, 2.5]);
+        assert_eq!(efficiencies, vec![1.0, 1.0, 0.625]);
+    }
+
+    #[test]
+    fn missing_file() {
+        assert!(bench_file("not_a_file.mtx", &BenchConfig::default()).is_err());
+    }
+}
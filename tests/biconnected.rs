@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::biconnected::{biconnectivity, Biconnectivity};
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use io_util::prelude::read_from_file;
+
+    use std::collections::{HashMap, HashSet};
+
+    /// Number of connected components of `edges` over `nodes`, skipping `removed`
+    fn count(nodes: &[u32], edges: &[(u32, u32)], removed: Option<u32>) -> usize {
+        let mut adjacency: HashMap<u32, Vec<u32>> = HashMap::new();
+        for &(a, b) in edges.iter().filter(|&&(a, b)| a != b && Some(a) != removed && Some(b) != removed) {
+            adjacency.entry(a).or_default().push(b);
+            adjacency.entry(b).or_default().push(a);
+        }
+
+        let mut seen = HashSet::new();
+        let mut components = 0;
+        for &v in nodes.iter().filter(|&&v| Some(v) != removed) {
+            if !seen.insert(v) {
+                continue;
+            }
+            components += 1;
+            let mut stack = vec![v];
+            while let Some(u) = stack.pop() {
+                for &w in adjacency.get(&u).into_iter().flatten() {
+                    if seen.insert(w) {
+                        stack.push(w);
+                    }
+                }
+            }
+        }
+        components
+    }
+
+    fn check(edges: &[(u32, u32)], components: &[Biconnectivity<u32>]) {
+        let mut all: Vec<u32> = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+        all.sort_unstable();
+        all.dedup();
+        let mut input: Vec<(u32, u32)> = edges.iter().filter(|(a, b)| a != b).map(|&(a, b)| (a.min(b), a.max(b))).collect();
+        input.sort_unstable();
+        input.dedup();
+        let base = count(&all, &input, None);
+
+        // blocks partition the edges
+        let mut blocked: Vec<(u32, u32)> = components.iter().flat_map(|c| c.blocks.iter().flatten().copied()).collect();
+        blocked.sort_unstable();
+        assert_eq!(blocked, input);
+
+        for c in components {
+            for &v in &c.articulation_points {
+                assert!(count(&all, &input, Some(v)) > base, "{v} is not an articulation point");
+            }
+            for &(a, b) in &c.bridges {
+                let without: Vec<(u32, u32)> = input.iter().copied().filter(|&e| e != (a, b)).collect();
+                assert!(count(&all, &without, None) > base, "{a}-{b} is not a bridge");
+            }
+
+            // every other node keeps the graph connected, and the blocks form a tree over the articulation points
+            let points: HashSet<u32> = c.articulation_points.iter().copied().collect();
+            let mut nodes: Vec<u32> = c.blocks.iter().flatten().flat_map(|&(a, b)| [a, b]).collect();
+            nodes.sort_unstable();
+            nodes.dedup();
+            for v in nodes.iter().filter(|v| !points.contains(v)) {
+                assert_eq!(count(&all, &input, Some(*v)), base, "{v}");
+            }
+
+            let mut memberships = 0;
+            for block in &c.blocks {
+                let mut members: Vec<u32> = block.iter().flat_map(|&(a, b)| [a, b]).collect();
+                members.sort_unstable();
+                members.dedup();
+                memberships += members.iter().filter(|v| points.contains(v)).count();
+
+                // 2-connected: no node of the block splits it
+                if block.len() > 1 {
+                    for &v in &members {
+                        assert_eq!(count(&members, block, Some(v)), 1, "block of {v}");
+                    }
+                } else {
+                    assert!(c.bridges.contains(&block[0]));
+                }
+            }
+            assert_eq!(c.blocks.len() + c.articulation_points.len(), memberships + usize::from(c.nodes > 1));
+        }
+    }
+
+    #[test]
+    fn example() {
+        let edges = read_from_file::<u32>("files/example.mtx").unwrap();
+
+        for variant in Variant::ALL {
+            let graph = variant.graph(&edges);
+            let components = biconnectivity(&graph, &variant.crack(&graph).components());
+
+            assert_eq!(components.len(), 2);
+            assert_eq!(components[0].articulation_points, vec![1, 2, 3]);
+            assert_eq!(components[0].bridges, vec![(0, 1), (1, 2), (3, 6)]);
+            assert_eq!(components[0].blocks.len(), 4);
+            assert_eq!(components[1].bridges, vec![(9, 10)]);
+            check(&edges, &components);
+        }
+    }
+
+    #[test]
+    fn generated() {
+        let specs = [("planted:80,100,3", 1), ("planted:60,75,2", 5), ("er:50,70", 2), ("grid:6,7", 1), ("comb:5,4", 1), ("caterpillar:12,2", 1)];
+        for (spec, seed) in specs {
+            let edges = spec.parse::<Generator>().unwrap().generate(seed).unwrap().edges;
+            let graph = Variant::Ep.graph(&edges);
+            let components = biconnectivity(&graph, &Variant::Ep.crack(&graph).components());
+
+            check(&edges, &components);
+
+            // the parallel passes give the same result on any number of threads
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
+            assert_eq!(pool.install(|| biconnectivity(&graph, &Variant::Ep.crack(&graph).components())), components, "{spec}");
+        }
+
+        let edges = read_from_file::<u32>("files/bio-diseasome.mtx").unwrap();
+        let graph = Variant::Ep.graph(&edges);
+        check(&edges, &biconnectivity(&graph, &Variant::Ep.crack(&graph).components()));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::bipartite::{bipartiteness, Coloring};
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use io_util::prelude::read_from_file;
+
+    use std::collections::HashSet;
+
+    fn check(edges: &[(u32, u32)], variant: Variant) -> (usize, usize) {
+        let graph = variant.graph(edges);
+        let components = variant.crack(&graph).components();
+        let result = bipartiteness(&graph, &components);
+        let input: HashSet<(u32, u32)> = edges.iter().flat_map(|&(a, b)| [(a, b), (b, a)]).collect();
+
+        assert_eq!(result.len(), components.len());
+        for (c, b) in result.iter().enumerate() {
+            assert_eq!(b.seed, components.seed(c));
+            match &b.coloring {
+                Coloring::Bipartite { sides } => assert_eq!(sides[0] + sides[1], b.nodes),
+                Coloring::OddCycle { cycle } => {
+                    assert_eq!(cycle.len() % 2, 1);
+                    assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+                    for (i, &v) in cycle.iter().enumerate() {
+                        assert!(components.same_component(v, b.seed));
+                        assert!(input.contains(&(v, cycle[(i + 1) % cycle.len()])), "{v} in {cycle:?}");
+                    }
+                }
+            }
+        }
+
+        let bipartite = result.iter().filter(|b| b.is_bipartite()).count();
+        (bipartite, result.len() - bipartite)
+    }
+
+    #[test]
+    fn even_and_odd_cycles() {
+        let even: Vec<(u32, u32)> = (0..6).map(|i| (i, (i + 1) % 6)).collect();
+        let odd: Vec<(u32, u32)> = (10..17).map(|i| (i, if i == 16 { 10 } else { i + 1 })).collect();
+        let edges: Vec<(u32, u32)> = even.into_iter().chain(odd).collect();
+
+        for variant in Variant::ALL {
+            assert_eq!(check(&edges, variant), (1, 1));
+        }
+    }
+
+    #[test]
+    fn generated() {
+        // forests are bipartite, dense random graphs are not
+        assert_eq!(check(&"planted:200,196,4".parse::<Generator>().unwrap().generate(1).unwrap().edges, Variant::Ep), (4, 0));
+        assert_eq!(check(&"planted:200,1200,4".parse::<Generator>().unwrap().generate(2).unwrap().edges, Variant::Ep), (0, 4));
+        assert_eq!(check(&"grid:30,40".parse::<Generator>().unwrap().generate(1).unwrap().edges, Variant::Ep), (1, 0));
+    }
+
+    #[test]
+    fn parallel_coloring() {
+        // a self-loop is an odd cycle of one node
+        let edges = [(1, 2), (2, 3), (3, 3), (5, 6)];
+        let graph = Variant::Ep.graph(&edges);
+        let result = bipartiteness(&graph, &Variant::Ep.crack(&graph).components());
+        assert_eq!(result.iter().map(|b| b.coloring.clone()).collect::<Vec<_>>(), vec![
+            Coloring::OddCycle { cycle: vec![3] },
+            Coloring::Bipartite { sides: [1, 1] },
+        ]);
+
+        // same sides and same witness on any number of threads
+        let edges = read_from_file::<u32>("files/soc-wiki-Vote.mtx").unwrap();
+        let graph = Variant::Ep.graph(&edges);
+        let components = Variant::Ep.crack(&graph).components();
+        let expected = bipartiteness(&graph, &components);
+        for num_threads in [1, 3] {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+            assert_eq!(pool.install(|| bipartiteness(&graph, &components)), expected);
+        }
+        check(&edges, Variant::Ep);
+    }
+}
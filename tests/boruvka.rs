@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use concurrent_graph::ConcurrentWeightedUnGraph;
+    use graph_example::boruvka::{boruvka, write_forest};
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::union_find::UnionFind;
+    use io_util::prelude::{read_weighted_from_file, GraphFormat};
+
+    use std::{fs::File, io::BufWriter};
+
+    fn kruskal(n: usize, edges: &[(u32, u32, f64)]) -> f64 {
+        let mut sorted = edges.to_vec();
+        sorted.sort_by(|a, b| a.2.total_cmp(&b.2));
+        let mut uf = UnionFind::new(n);
+        sorted.into_iter().filter(|&(a, b, _)| uf.union(a as usize, b as usize)).map(|e| e.2).sum()
+    }
+
+    #[test]
+    fn example() {
+        let edges = read_weighted_from_file::<u32, f64>("files/example_weighted.mtx").unwrap();
+        let forest = boruvka(&ConcurrentWeightedUnGraph::from_edges(&edges));
+
+        // the heaviest edge of the only cycle is left out
+        assert_eq!(forest.edges.len(), 9);
+        assert!(!forest.edges.contains(&(5, 8, 0.85)));
+        assert_eq!(forest.trees.len(), 2);
+        assert_eq!((forest.trees[1].seed, forest.trees[1].nodes, forest.trees[1].weight), (9, 2, 0.75));
+        assert!((forest.weight() - kruskal(11, &edges)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn matches_kruskal() {
+        for (spec, seed, ties) in [("planted:500,2000,7", 1, 1000), ("planted:400,1500,3", 2, 5), ("er:300,900", 3, 20)] {
+            let generated = spec.parse::<Generator>().unwrap().generate(seed).unwrap().edges;
+            let mut state = seed;
+            let edges: Vec<(u32, u32, f64)> = generated
+                .iter()
+                .map(|&(a, b)| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    (a, b, ((state >> 33) % ties) as f64)
+                })
+                .collect();
+
+            let graph = ConcurrentWeightedUnGraph::from_edges(&edges);
+            let forest = boruvka(&graph);
+            let res = Variant::Ep.run(&generated);
+
+            assert_eq!(forest.trees.len(), res.num_components(), "{spec}");
+            assert_eq!(forest.edges.len(), graph.node_count() - res.num_components(), "{spec}");
+            let ids = 1 + generated.iter().map(|e| e.0.max(e.1) as usize).max().unwrap();
+            assert_eq!(forest.weight(), kruskal(ids, &edges), "{spec}");
+            for &(a, b, w) in &forest.edges {
+                assert_eq!(graph.weight(a, b), Some(w));
+                assert!(res.components().same_component(a, b));
+            }
+            for tree in &forest.trees {
+                assert_eq!(tree.edges + 1, tree.nodes);
+            }
+        }
+    }
+
+    #[test]
+    fn write_forest_roundtrip() {
+        let edges = read_weighted_from_file::<u32, f64>("files/example_weighted.mtx").unwrap();
+        let forest = boruvka(&ConcurrentWeightedUnGraph::from_edges(&edges));
+
+        for (extension, format) in [("el", GraphFormat::EdgeList), ("mtx", GraphFormat::MatrixMarket)] {
+            let path = std::env::temp_dir().join(format!("boruvka_forest_{}.{extension}", std::process::id()));
+            let filename = path.to_str().unwrap();
+            write_forest(BufWriter::new(File::create(filename).unwrap()), &forest.edges, 11, format).unwrap();
+
+            // headerless `a b w` lines load back like the MatrixMarket file
+            assert_eq!(read_weighted_from_file::<u32, f64>(filename).unwrap(), forest.edges, "{extension}");
+            std::fs::remove_file(filename).unwrap();
+        }
+    }
+}
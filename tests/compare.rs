@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::compare::{compare, read_labels, Labels, Split};
+
+    use std::{env, fs, process::Command};
+
+    fn labels(pairs: &[(u64, u64)]) -> Labels {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn identical_up_to_relabeling() {
+        let left = labels(&[(1, 1), (2, 1), (3, 3), (4, 3), (5, 5)]);
+        let right = labels(&[(1, 7), (2, 7), (3, 0), (4, 0), (5, 2)]);
+
+        let res = compare(&left, &right);
+        assert!(res.identical);
+        assert_eq!(res.nodes, 5);
+        assert_eq!((res.left_components, res.right_components), (3, 3));
+        assert_eq!(res.rand_index, 1.0);
+        assert_eq!(res.adjusted_rand_index, 1.0);
+        assert_eq!(res.variation_of_information, 0.0);
+        assert!(res.splits.is_empty() && res.merges.is_empty());
+    }
+
+    #[test]
+    fn splits_merges_and_metrics() {
+        // left {1, 2, 3} {4, 5} {6}, right {1, 2, 4, 5} {3, 7} {6}
+        let left = labels(&[(1, 1), (2, 1), (3, 1), (4, 4), (5, 4), (6, 6)]);
+        let right = labels(&[(1, 0), (2, 0), (3, 7), (4, 0), (5, 0), (6, 6), (7, 7)]);
+
+        let res = compare(&left, &right);
+        assert!(!res.identical);
+        assert_eq!((res.nodes, res.only_left, res.only_right), (6, 0, 1));
+        assert_eq!(res.splits, vec![Split { label: 1, size: 3, parts: vec![(0, 2), (7, 1)] }]);
+        assert_eq!(res.merges, vec![Split { label: 0, size: 4, parts: vec![(1, 2), (4, 2)] }]);
+
+        // 15 pairs: 2 together in both, 7 apart in both
+        assert!((res.rand_index - 9.0 / 15.0).abs() < 1e-12);
+        assert!((res.adjusted_rand_index - 0.4 / 3.4).abs() < 1e-12);
+
+        // H(left | right) + H(right | left)
+        let vi = (2.0 / 6.0) * ((3.0f64 / 2.0).ln() + 2.0f64.ln()) + (1.0 / 6.0) * 3.0f64.ln() + (2.0 / 6.0) * 2.0f64.ln();
+        assert!((res.variation_of_information - vi).abs() < 1e-12);
+
+        // symmetric
+        let back = compare(&right, &left);
+        assert_eq!(back.splits, res.merges);
+        assert!((back.adjusted_rand_index - res.adjusted_rand_index).abs() < 1e-12);
+    }
+
+    #[test]
+    fn read_label_files() {
+        let path = env::temp_dir().join(format!("labels_{}.txt", std::process::id()));
+        fs::write(&path, "# node label\n1 0\n2 0\n\n3 3\n").unwrap();
+        assert_eq!(read_labels(path.to_str().unwrap()).unwrap(), labels(&[(1, 0), (2, 0), (3, 3)]));
+
+        fs::write(&path, "1 0\n2\n").unwrap();
+        assert!(read_labels(path.to_str().unwrap()).is_err());
+
+        // a node listed twice is not silently overwritten
+        fs::write(&path, "1 0\n2 0\n1 5\n").unwrap();
+        let e = read_labels(path.to_str().unwrap()).unwrap_err();
+        assert!(e.to_string().contains(":3: node 1"), "{e}");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn diff_exit_status() {
+        let dir = env::temp_dir();
+        let left = dir.join(format!("diff_left_{}.txt", std::process::id()));
+        let right = dir.join(format!("diff_right_{}.txt", std::process::id()));
+        fs::write(&left, "1 0\n2 0\n3 3\n").unwrap();
+        fs::write(&right, "1 7\n2 7\n3 7\n").unwrap();
+
+        let status = |args: &[&std::path::Path]| {
+            Command::new(env!("CARGO_BIN_EXE_diff")).args(args).output().unwrap().status.code()
+        };
+        assert_eq!(status(&[&left, &left]), Some(0));
+        assert_eq!(status(&[&left, &right]), Some(1));
+        assert_eq!(status(&[&left]), Some(2));
+        assert_eq!(status(&[&left, &right, &left]), Some(2));
+        assert_eq!(status(&[&left, &dir.join("missing_labels.txt")]), Some(2));
+        assert_eq!(status(&[std::path::Path::new("-h")]), Some(0));
+
+        fs::remove_file(&left).unwrap();
+        fs::remove_file(&right).unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::components::{write_labels, Bucket, ComponentReport, ComponentStats, Components, Labeling};
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+
+    use dashmap::DashMap;
+    use io_util::prelude::{read_from_file, read_num_nodes, write_to_file, GraphFormat};
+
+    #[test]
+    fn stats_from_sizes() {
+        let stats = ComponentStats::new(&[1, 1, 2, 3, 9, 4]);
+
+        assert_eq!(stats.nodes, 20);
+        assert_eq!(stats.components, 6);
+        assert_eq!(stats.largest, 9);
+        assert_eq!(stats.smallest, 1);
+        assert_eq!(stats.singletons, 2);
+        assert_eq!(stats.giant_fraction, 0.45);
+        assert_eq!(
+            stats.histogram,
+            vec![
+                Bucket { min: 1, max: 1, count: 2 },
+                Bucket { min: 2, max: 3, count: 2 },
+                Bucket { min: 4, max: 7, count: 1 },
+                Bucket { min: 8, max: 15, count: 1 },
+            ]
+        );
+
+        assert_eq!(ComponentStats::new(&[]), ComponentStats::default());
+    }
+
+    #[test]
+    fn stats_from_cracker() {
+        let edges = "planted:500,1000,7".parse::<Generator>().unwrap().generate(3).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let res = variant.run(&edges);
+            let stats = ComponentStats::from_cracker(&res);
+
+            assert_eq!(stats.nodes, 500, "{}", variant.name());
+            assert_eq!(stats.components, 7);
+            assert_eq!(stats.largest, res.largest_component());
+            assert_eq!(stats.histogram.iter().map(|b| b.count).sum::<usize>(), 7);
+        }
+    }
+
+    #[test]
+    fn stats_with_isolated() {
+        assert_eq!(ComponentStats::new(&[2, 9]).with_isolated(2), ComponentStats::new(&[2, 9, 1, 1]));
+        assert_eq!(ComponentStats::new(&[1, 4]).with_isolated(3), ComponentStats::new(&[1, 4, 1, 1, 1]));
+        assert_eq!(ComponentStats::default().with_isolated(1), ComponentStats::new(&[1]));
+        assert_eq!(ComponentStats::new(&[3]).with_isolated(0), ComponentStats::new(&[3]));
+
+        // 0..6 declared, only 0, 1 and 2 appear in an edge
+        let filename = std::env::temp_dir().join(format!("components_isolated_{}.mtx", std::process::id()));
+        let filename = filename.to_str().unwrap();
+        write_to_file(filename, &[(0u32, 1u32), (1, 2)], 6, GraphFormat::MatrixMarket).unwrap();
+        let edges = read_from_file::<u32>(filename).unwrap();
+        let num_nodes = read_num_nodes(filename).unwrap();
+        std::fs::remove_file(filename).unwrap();
+
+        let graph = Variant::Ep.graph(&edges);
+        let stats = ComponentStats::from_cracker(&Variant::Ep.crack(&graph)).with_isolated(num_nodes.unwrap() - graph.node_count());
+        assert_eq!((stats.nodes, stats.components, stats.singletons), (6, 4, 3));
+
+        assert_eq!(read_num_nodes("files/example.mtx").unwrap(), Some(11));
+        assert_eq!(read_num_nodes("files/example_weighted.mtx").unwrap(), Some(11));
+        assert!(read_num_nodes("files/missing.mtx").is_err());
+    }
+
+    #[test]
+    fn report_formats() {
+        let stats = ComponentStats::new(&[2, 9]);
+        let text = stats.to_string();
+        assert!(text.contains("components: 2\n"));
+        assert!(text.contains("largest component: 9 (81.82% of the nodes)\n"));
+        assert!(text.contains("   8 - 15: 1\n"));
+
+        let report = ComponentReport { file: "example.mtx", variant: "ep", stats: &stats };
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["file"], "example.mtx");
+        assert_eq!(json["components"], 2);
+        assert_eq!(json["histogram"][3]["count"], 1);
+    }
+
+    #[test]
+    fn query_api() {
+        // {1, 2, 3} {4} {7, 9}
+        let seeds: DashMap<u32, u32> = [(1, 1), (2, 1), (3, 1), (4, 4), (7, 7), (9, 7)].into_iter().collect();
+        let components = Components::new(&seeds);
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(components.num_nodes(), 6);
+        assert_eq!(components.sizes().collect::<Vec<_>>(), vec![3, 1, 2]);
+        assert_eq!(components.members(2), &[7, 9]);
+        assert_eq!(components.seed(1), 4);
+
+        assert_eq!(components.component_of(3), Some(0));
+        assert_eq!(components.component_of(9), Some(2));
+        assert_eq!(components.component_of(5), None);
+        assert!(components.same_component(1, 3));
+        assert!(!components.same_component(3, 4));
+        assert!(!components.same_component(5, 5));
+
+        let members: Vec<&[u32]> = components.iter().collect();
+        assert_eq!(members, vec![&[1, 2, 3][..], &[4], &[7, 9]]);
+        assert_eq!((&components).into_iter().count(), 3);
+
+        assert!(Components::<u32>::new(&DashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn components_of_cracker() {
+        let edges = "planted:500,1000,7".parse::<Generator>().unwrap().generate(5).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let res = variant.run(&edges);
+            let components = res.components();
+
+            assert_eq!(components.len(), res.num_components(), "{}", variant.name());
+            assert_eq!(components.num_nodes(), 500);
+            assert_eq!(components.sizes().max(), Some(res.largest_component()));
+
+            for (a, b) in &edges {
+                assert!(components.same_component(*a, *b));
+            }
+            for (c, members) in components.iter().enumerate() {
+                assert!(members.iter().all(|&v| components.component_of(v) == Some(c)));
+            }
+        }
+    }
+
+    fn labels(components: Components<u32>, labeling: Labeling) -> String {
+        let mut out = Vec::new();
+        write_labels(&mut out, components, labeling).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn canonical_labels() {
+        // {5, 1} seeded by 5, {2, 3, 9} seeded by 9, {4}
+        let seeds: DashMap<u32, u32> = [(1, 5), (5, 5), (2, 9), (3, 9), (9, 9), (4, 4)].into_iter().collect();
+        let components = Components::new(&seeds);
+
+        assert_eq!(labels(components.clone(), Labeling::Seed), "1 5\n2 9\n3 9\n4 4\n5 5\n9 9\n");
+        assert_eq!(labels(components.clone(), Labeling::Min), "1 1\n2 2\n3 2\n4 4\n5 1\n9 2\n");
+        assert_eq!(labels(components.clone(), Labeling::DenseMin), "1 0\n2 1\n3 1\n4 2\n5 0\n9 1\n");
+        assert_eq!(labels(components.clone(), Labeling::DenseSize), "1 1\n2 0\n3 0\n4 2\n5 1\n9 0\n");
+
+        let by_size = components.by_size();
+        assert_eq!(by_size.members(0), &[2, 3, 9]);
+        assert_eq!(by_size.seed(0), 9);
+        assert_eq!(by_size.component_of(4), Some(2));
+
+        assert_eq!("dense-size".parse::<Labeling>(), Ok(Labeling::DenseSize));
+        assert!("size".parse::<Labeling>().is_err());
+    }
+
+    #[test]
+    fn labels_independent_of_variant_and_threads() {
+        let edges = "planted:2000,3000,40".parse::<Generator>().unwrap().generate(6).unwrap().edges;
+        let expected = labels(Variant::Base.run(&edges).components(), Labeling::DenseSize);
+
+        for num_threads in [1, 3] {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+
+            for variant in Variant::ALL {
+                let res = pool.install(|| variant.run(&edges));
+                assert_eq!(labels(res.components(), Labeling::DenseSize), expected, "{}", variant.name());
+                assert_eq!(labels(res.components(), Labeling::Min), labels(res.components().by_min(), Labeling::Min));
+            }
+        }
+    }
+}
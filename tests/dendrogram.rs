@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::dendrogram::{dendrogram, distinct_weights, quantile_weights, Merge};
+    use graph_example::generators::Generator;
+    use graph_example::union_find::UnionFind;
+    use graph_example::weighted::min_weight;
+    use io_util::prelude::read_weighted_from_file;
+
+    use std::collections::{HashMap, HashSet};
+
+    /// {node: smallest node of its cluster} after applying the merges down to `threshold`
+    fn clusters(merges: &[Merge<u32>], threshold: f64) -> HashMap<u32, u32> {
+        let mut cluster = HashMap::new();
+        for m in merges.iter().take_while(|m| m.threshold >= threshold) {
+            let right = *cluster.entry(m.right).or_insert(m.right);
+            cluster.entry(m.left).or_insert(m.left);
+            for label in cluster.values_mut().filter(|label| **label == right) {
+                *label = m.left;
+            }
+        }
+        cluster
+    }
+
+    #[test]
+    fn example() {
+        let edges = read_weighted_from_file::<u32, f64>("files/example_weighted.mtx").unwrap();
+        let merges = dendrogram(&edges, &distinct_weights(&edges), Variant::Ep).unwrap();
+
+        // 11 nodes, 2 components at the bottom
+        assert_eq!(merges.len(), 9);
+        assert_eq!(merges[0], Merge { threshold: 0.95, left: 3, right: 6, size: 2 });
+        assert_eq!(merges.last().unwrap(), &Merge { threshold: 0.4, left: 0, right: 2, size: 9 });
+    }
+
+    #[test]
+    fn quantile_levels() {
+        let edges = read_weighted_from_file::<u32, f64>("files/example_weighted.mtx").unwrap();
+
+        // 10 distinct weights: ranks 3, 6, 10 (the smallest)
+        assert_eq!(quantile_weights(&edges, 3), vec![0.85, 0.7, 0.3]);
+        assert_eq!(quantile_weights(&edges, 1), vec![0.3]);
+        assert_eq!(quantile_weights(&edges, 10), distinct_weights(&edges));
+        assert_eq!(quantile_weights(&edges, 50), distinct_weights(&edges));
+
+        // same final clusters, every merge rounded down to the next level
+        let exact = dendrogram(&edges, &distinct_weights(&edges), Variant::Ep).unwrap();
+        let coarse = dendrogram(&edges, &quantile_weights(&edges, 3), Variant::Ep).unwrap();
+        assert_eq!(coarse.len(), exact.len());
+        assert_eq!(clusters(&coarse, 0.3), clusters(&exact, 0.3));
+        for level in [0.85, 0.7] {
+            assert_eq!(clusters(&coarse, level), clusters(&exact, level));
+        }
+        assert!(coarse.iter().all(|m| [0.85, 0.7, 0.3].contains(&m.threshold)));
+    }
+
+    #[test]
+    fn matches_thresholded_components() {
+        let mut state = 7u64;
+        let edges: Vec<(u32, u32, f64)> = "planted:300,600,5"
+            .parse::<Generator>()
+            .unwrap()
+            .generate(4)
+            .unwrap()
+            .edges
+            .into_iter()
+            .map(|(a, b)| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (a, b, ((state >> 33) % 50) as f64 / 50.0)
+            })
+            .collect();
+
+        let thresholds = distinct_weights(&edges);
+        for variant in Variant::ALL {
+            let merges = dendrogram(&edges, &thresholds, variant).unwrap();
+            assert_eq!(merges.len(), 300 - 5, "{}", variant.name());
+            assert!(merges.windows(2).all(|w| w[0].threshold >= w[1].threshold));
+
+            for &tau in thresholds.iter().step_by(7) {
+                let filtered = min_weight(&edges, tau);
+                let res = Variant::Ep.run(&filtered);
+                let cluster = clusters(&merges, tau);
+
+                // the clusters are unions of components with as many clusters as components: the same partition
+                assert!(filtered.iter().all(|(a, b)| cluster[a] == cluster[b]));
+                assert_eq!(cluster.len(), res.seeds.len());
+                assert_eq!(cluster.values().collect::<HashSet<_>>().len(), res.num_components());
+            }
+        }
+
+        // merge thresholds are the weights Kruskal joins two trees at
+        let mut sorted = edges.clone();
+        sorted.sort_by(|a, b| b.2.total_cmp(&a.2));
+        let mut uf = UnionFind::new(300);
+        let kruskal: Vec<f64> = sorted.into_iter().filter(|&(a, b, _)| uf.union(a as usize, b as usize)).map(|e| e.2).collect();
+        let merges: Vec<f64> = dendrogram(&edges, &thresholds, Variant::Ep).unwrap().iter().map(|m| m.threshold).collect();
+        assert_eq!(merges, kruskal);
+    }
+
+    #[test]
+    fn non_finite_weights() {
+        let nan = vec![(0u32, 1u32, 0.5), (1, 2, f64::NAN), (2, 3, 0.9)];
+        assert!(dendrogram(&nan, &[0.9, 0.5], Variant::Ep).is_err());
+
+        let inf = vec![(0u32, 1u32, 0.5), (1, 2, f64::INFINITY)];
+        assert!(dendrogram(&inf, &[0.5], Variant::Ep).is_err());
+
+        let edges = vec![(0u32, 1u32, 0.5)];
+        assert!(dendrogram(&edges, &[f64::NAN, 0.5], Variant::Ep).is_err());
+    }
+}
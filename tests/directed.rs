@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::components::Components;
+    use graph_example::cracker::Variant;
+    use graph_example::directed::{arc_graph, strongly_connected_components, weakly_connected_components};
+    use graph_example::generators::Generator;
+
+    use std::collections::HashSet;
+
+    fn reachable(arcs: &[(u32, u32)], from: u32) -> HashSet<u32> {
+        let mut seen = HashSet::from([from]);
+        let mut stack = vec![from];
+        while let Some(u) = stack.pop() {
+            for &(_, b) in arcs.iter().filter(|&&(a, _)| a == u) {
+                if seen.insert(b) {
+                    stack.push(b);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Pseudo-random arcs over 0..n, every node appears
+    fn random_arcs(n: u32, m: usize, mut state: u64) -> Vec<(u32, u32)> {
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 33) % n as u64) as u32
+        };
+        let mut arcs: Vec<(u32, u32)> = (0..m).map(|_| (next(), next())).collect();
+        arcs.extend((0..n).map(|v| (v, v)));
+        arcs
+    }
+
+    #[test]
+    fn weak_components_match_undirected() {
+        let edges = "planted:400,900,6".parse::<Generator>().unwrap().generate(3).unwrap().edges;
+
+        let weak = weakly_connected_components(&arc_graph(&edges)).components().by_min();
+        let undirected = Variant::Ep.run(&edges).components().by_min();
+
+        assert_eq!(weak.len(), 6);
+        assert_eq!(weak.labelled().collect::<Vec<_>>(), undirected.labelled().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn strong_components_match_reachability() {
+        for (seed, m) in [(1, 20), (2, 40), (3, 60), (4, 90)] {
+            let n = 40;
+            let arcs = random_arcs(n, m, seed);
+            let scc = strongly_connected_components(&arc_graph(&arcs));
+            assert_eq!(scc.len(), n as usize);
+
+            let reach: Vec<HashSet<u32>> = (0..n).map(|v| reachable(&arcs, v)).collect();
+            for u in 0..n {
+                for v in 0..n {
+                    let same = reach[u as usize].contains(&v) && reach[v as usize].contains(&u);
+                    assert_eq!(*scc.get(&u).unwrap() == *scc.get(&v).unwrap(), same, "seed {seed}: {u} {v}");
+                }
+                // labelled by the smallest member
+                assert!(*scc.get(&u).unwrap() <= u);
+            }
+        }
+    }
+
+    #[test]
+    fn strong_components_of_cycle_and_tail() {
+        let scc = Components::new(&strongly_connected_components(&arc_graph(&[(1u32, 2), (2, 3), (3, 1), (3, 4)])));
+
+        assert_eq!(scc.len(), 2);
+        assert!(scc.same_component(1, 3));
+        assert!(!scc.same_component(3, 4));
+    }
+}
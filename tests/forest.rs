@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::forest::{verify_forest, Witnesses};
+    use graph_example::generators::Generator;
+    use graph_example::union_find::UnionFind;
+    use io_util::prelude::read_from_file;
+
+    #[test]
+    fn union_find() {
+        let mut sets = UnionFind::new(5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(!sets.union(1, 0));
+        assert!(sets.union(1, 4));
+
+        assert_eq!(sets.find(0), sets.find(3));
+        assert_ne!(sets.find(0), sets.find(2));
+        assert_eq!(sets.size(4), 4);
+        assert_eq!(sets.size(2), 1);
+    }
+
+    #[test]
+    fn forest_of_generated_graphs() {
+        for spec in ["planted:600,1500,9", "grid:20,30", "caterpillar:50,2", "rmat:10,3000"] {
+            let edges = spec.parse::<Generator>().unwrap().generate(7).unwrap().edges;
+
+            for variant in Variant::ALL {
+                let witnesses = Witnesses::new();
+                let components = variant.crack_witnessed(&variant.graph(&edges), &mut (), &witnesses).components();
+                let forest = witnesses.spanning_forest();
+
+                assert_eq!(verify_forest(&forest, &edges, &components), Ok(()), "{spec} {}", variant.name());
+            }
+        }
+    }
+
+    #[test]
+    fn forest_of_datasets() {
+        for file in ["files/bio-diseasome.mtx", "files/soc-wiki-Vote.mtx"] {
+            let edges = read_from_file::<u32>(file).unwrap();
+
+            for variant in Variant::ALL {
+                let witnesses = Witnesses::new();
+                let components = variant.crack_witnessed(&variant.graph(&edges), &mut (), &witnesses).components();
+
+                assert_eq!(verify_forest(&witnesses.spanning_forest(), &edges, &components), Ok(()), "{file} {}", variant.name());
+            }
+        }
+    }
+
+    #[test]
+    fn forest_of_example() {
+        let edges = read_from_file::<u32>("files/example.mtx").unwrap();
+        let witnesses = Witnesses::new();
+        let components = Variant::Ep.crack_witnessed(&Variant::Ep.graph(&edges), &mut (), &witnesses).components();
+        let forest = witnesses.spanning_forest();
+
+        assert_eq!(forest.len(), 11 - 2);
+        assert!(forest.windows(2).all(|w| w[0] < w[1] && w[0].0 < w[0].1));
+        assert_eq!(verify_forest(&forest, &edges, &components), Ok(()));
+    }
+
+    #[test]
+    fn invalid_forests() {
+        // {1, 2, 3} {4, 5}
+        let edges = [(1, 2), (2, 3), (1, 3), (4, 5)];
+        let components = Variant::Ep.run::<u32>(&edges).components();
+
+        assert!(verify_forest(&[(1, 2), (2, 3), (4, 5)], &edges, &components).is_ok());
+        assert!(verify_forest(&[(2, 1), (3, 1), (5, 4)], &edges, &components).is_ok());
+
+        let not_input = verify_forest(&[(1, 2), (2, 3), (4, 1)], &edges, &components);
+        assert_eq!(not_input, Err("(4, 1) is not an input edge".to_string()));
+
+        let cycle = verify_forest(&[(1, 2), (2, 3), (1, 3)], &edges, &components);
+        assert_eq!(cycle, Err("(1, 3) closes a cycle".to_string()));
+
+        assert!(verify_forest(&[(1, 2), (4, 5)], &edges, &components).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use graph_example::cracker::Variant;
+    use graph_example::generators::{Generator, SyntheticGraph};
+    use graph_example::worst_case::{worst_case, Family};
+    use io_util::prelude::{read_from_file, write_to_file, GraphFormat};
+
+    fn generate(spec: &str, seed: u64) -> SyntheticGraph {
+        spec.parse::<Generator>().unwrap().generate(seed).unwrap()
+    }
+
+    /// No self-loops, no parallel edges, labels in range
+    fn assert_simple(g: &SyntheticGraph) {
+        let mut seen = HashSet::new();
+        for &(a, b) in &g.edges {
+            assert_ne!(a, b);
+            assert!((a as usize) < g.num_nodes && (b as usize) < g.num_nodes);
+            assert!(seen.insert((a.min(b), a.max(b))), "parallel edge {a} {b}");
+        }
+    }
+
+    #[test]
+    fn deterministic_test() {
+        for spec in ["er:100,300", "rmat:8,500", "ba:100,3", "planted:100,200,5"] {
+            assert_eq!(generate(spec, 42), generate(spec, 42), "{spec}");
+            assert_ne!(generate(spec, 42), generate(spec, 43), "{spec}");
+        }
+    }
+
+    #[test]
+    fn random_families_test() {
+        let er = generate("er:1_000,5_000", 1);
+        assert_eq!((er.num_nodes, er.edges.len()), (1000, 5000));
+        assert_simple(&er);
+
+        let rmat = generate("rmat:10,4000,0.45,0.15,0.15", 1);
+        assert_eq!((rmat.num_nodes, rmat.edges.len()), (1024, 4000));
+        assert_simple(&rmat);
+
+        let ba = generate("ba:500,4", 1);
+        assert_eq!(ba.edges.len(), 4 * (500 - 4));
+        assert_simple(&ba);
+        assert_eq!(Variant::Ep.run(&ba.edges).num_components(), 1);
+    }
+
+    #[test]
+    fn regular_families_test() {
+        let grid = generate("grid:3,4", 0);
+        assert_eq!((grid.num_nodes, grid.edges.len()), (12, 3 * 3 + 2 * 4));
+        assert_simple(&grid);
+
+        let star = generate("star:10", 0);
+        assert_eq!(star.edges.len(), 9);
+        assert!(star.edges.iter().all(|&(center, _)| center == 0));
+
+        let path = generate("path:10", 0);
+        assert_eq!(path.edges.len(), 9);
+
+        for g in [grid, star, path] {
+            let res = Variant::Base.run(&g.edges);
+            assert_eq!(res.num_components(), 1);
+            assert_eq!(res.seeds.len(), g.num_nodes);
+        }
+    }
+
+    #[test]
+    fn adversarial_families_test() {
+        assert_eq!(generate("zigzag:5", 0).edges, vec![(0, 4), (4, 1), (1, 3), (3, 2)]);
+
+        let caterpillar = generate("caterpillar:4,2", 0);
+        assert_eq!((caterpillar.num_nodes, caterpillar.edges.len()), (12, 11));
+
+        let comb = generate("comb:3,4", 0);
+        assert_eq!((comb.num_nodes, comb.edges.len()), (15, 14));
+
+        for family in Family::ALL {
+            let g = family.generator(100).generate(0).unwrap();
+            assert_simple(&g);
+
+            let res = Variant::EpOs.run(&g.edges);
+            assert_eq!(res.num_components(), 1, "{}", family.name());
+            assert_eq!(res.seeds.len(), g.num_nodes, "{}", family.name());
+        }
+    }
+
+    #[test]
+    fn tree_depth_test() {
+        assert_eq!(Variant::Ep.run(&generate("star:20", 0).edges).tree_depth(), 1);
+        assert_eq!(Variant::Ep.run(&generate("path:2", 0).edges).tree_depth(), 1);
+    }
+
+    #[test]
+    fn worst_case_logarithmic() {
+        for family in Family::ALL {
+            for variant in Variant::ALL {
+                let row = worst_case(family, 512, variant).unwrap();
+
+                assert!(row.tree_depth <= row.iterations, "{} {}", row.family, row.variant);
+                assert!(row.iterations as f64 <= row.log2_nodes + 1.0, "{} {}", row.family, row.variant);
+            }
+        }
+    }
+
+    #[test]
+    fn planted_test() {
+        let g = generate("planted:1000,3000,7", 3);
+        assert_eq!((g.num_nodes, g.edges.len()), (1000, 3000));
+        assert_simple(&g);
+
+        for variant in Variant::ALL {
+            let res = variant.run(&g.edges);
+            assert_eq!(res.num_components(), 7, "{}", variant.name());
+            assert_eq!(res.seeds.len(), 1000);
+        }
+    }
+
+    #[test]
+    fn invalid_specs() {
+        assert!("er:10".parse::<Generator>().is_err());
+        assert!("tree:10".parse::<Generator>().is_err());
+        assert!("er:10,x".parse::<Generator>().is_err());
+        assert!("rmat:4294967297,10".parse::<Generator>().is_err());
+        assert!("er:10,46".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("planted:10,20,6".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("ba:5,5".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("grid:10000000000,10000000000".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("caterpillar:10000000000,10000000000".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("caterpillar:2,18446744073709551615".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("comb:10000000000,10000000000".parse::<Generator>().unwrap().generate(0).is_err());
+        assert!("comb:2,18446744073709551615".parse::<Generator>().unwrap().generate(0).is_err());
+    }
+
+    #[test]
+    fn write_mtx_roundtrip() {
+        let g = generate("er:50,120", 7);
+        let file = std::env::temp_dir().join(format!("generators_roundtrip_{}.mtx", std::process::id()));
+        let file = file.to_str().unwrap();
+
+        write_to_file(file, &g.edges, g.num_nodes, GraphFormat::from_path(file)).unwrap();
+        let edges = read_from_file::<u32>(file).unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        assert_eq!(edges, g.edges);
+    }
+
+    #[test]
+    fn write_el_roundtrip() {
+        let g = generate("path:5", 0);
+        let file = std::env::temp_dir().join(format!("generators_roundtrip_{}.el", std::process::id()));
+        let file = file.to_str().unwrap();
+
+        write_to_file(file, &g.edges, g.num_nodes, GraphFormat::from_path(file)).unwrap();
+        let edges = read_from_file::<u32>(file).unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        assert_eq!(edges, g.edges);
+        let res = Variant::Ep.run(&edges);
+        assert_eq!((res.num_components(), res.largest_component()), (1, 5));
+
+        // bundled files use a single `%` before MatrixMarket
+        assert_eq!(read_from_file::<u32>("files/example.mtx").unwrap().len(), 10);
+    }
+
+    #[test]
+    fn malformed_files_are_errors() {
+        let dir = std::env::temp_dir();
+        for (name, content) in [
+            ("short_line.el", "0 1\n2\n"),
+            ("not_a_node.el", "0 1\nx 2\n"),
+            ("no_size_line.mtx", "%%MatrixMarket matrix coordinate pattern symmetric\n"),
+            ("bad_size_line.mtx", "%%MatrixMarket matrix coordinate pattern symmetric\n3 3\n0 1\n"),
+        ] {
+            let file = dir.join(format!("{}_{name}", std::process::id()));
+            std::fs::write(&file, content).unwrap();
+            let read = read_from_file::<u32>(file.to_str().unwrap());
+            std::fs::remove_file(&file).unwrap();
+
+            assert!(read.is_err(), "{name}");
+        }
+    }
+}
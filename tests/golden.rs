@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+    };
+
+    use concurrent_graph::GraphTrait;
+    use graph_example::cracker::{undirected_graph, Variant};
+    use graph_example::datasets::{read_manifest, Dataset};
+    use graph_example::union_find::UnionFind;
+    use io_util::prelude::read_from_file;
+
+    type V = u32;
+
+    fn files_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("files")
+    }
+
+    fn datasets() -> Vec<Dataset> {
+        read_manifest(&files_dir().join("manifest.csv")).unwrap()
+    }
+
+    fn load(dataset: &Dataset) -> Vec<(V, V)> {
+        read_from_file::<V>(dataset.path(&files_dir()).to_str().unwrap()).unwrap()
+    }
+
+    /// {node: component root} by union-find over the edges, independent of CRACKER
+    fn reference(edges: &[(V, V)]) -> HashMap<V, usize> {
+        let mut index: HashMap<V, usize> = HashMap::new();
+        for &(a, b) in edges {
+            for v in [a, b] {
+                let next = index.len();
+                index.entry(v).or_insert(next);
+            }
+        }
+
+        let mut sets = UnionFind::new(index.len());
+        for (a, b) in edges {
+            sets.union(index[a], index[b]);
+        }
+
+        index.into_iter().map(|(v, i)| (v, sets.find(i))).collect()
+    }
+
+    #[test]
+    fn manifest_matches_reference() {
+        for dataset in datasets() {
+            let roots = reference(&load(&dataset));
+            let mut sizes: HashMap<usize, usize> = HashMap::new();
+            for root in roots.values() {
+                *sizes.entry(*root).or_default() += 1;
+            }
+
+            assert_eq!(roots.len(), dataset.nodes, "{}", dataset.file);
+            assert_eq!(sizes.len(), dataset.components, "{}", dataset.file);
+            assert_eq!(sizes.values().max().copied().unwrap_or(0), dataset.largest_component, "{}", dataset.file);
+        }
+    }
+
+    fn check_variant(variant: Variant) {
+        for dataset in datasets() {
+            let edges = load(&dataset);
+            let res = variant.run(&edges);
+            assert_eq!(res.seeds.len(), dataset.nodes, "{}: {} lost nodes", variant.name(), dataset.file);
+
+            // same partition as the reference: seeds and roots are in bijection
+            let mut seed_of_root: HashMap<usize, V> = HashMap::new();
+            for (v, root) in reference(&edges) {
+                let seed = *res.seeds.get(&v).unwrap();
+                assert_eq!(*seed_of_root.entry(root).or_insert(seed), seed, "{}: {} split", variant.name(), dataset.file);
+            }
+
+            assert_eq!(res.num_components(), dataset.components, "{}: {}", variant.name(), dataset.file);
+            assert_eq!(res.largest_component(), dataset.largest_component, "{}: {}", variant.name(), dataset.file);
+        }
+    }
+
+    #[test]
+    fn manifest_covers_bundled_files() {
+        let datasets = datasets();
+        assert!(!datasets.is_empty());
+
+        for dataset in datasets {
+            let edges = load(&dataset);
+            assert_eq!(edges.len(), dataset.edges, "{}", dataset.file);
+            assert_eq!(undirected_graph(&edges).node_count(), dataset.nodes, "{}", dataset.file);
+        }
+    }
+
+    #[test]
+    fn base_golden() {
+        check_variant(Variant::Base);
+    }
+
+    #[test]
+    fn ep_golden() {
+        check_variant(Variant::Ep);
+    }
+
+    #[test]
+    fn epos_golden() {
+        check_variant(Variant::EpOs);
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use concurrent_graph::{ConcurrentDiGraph, ConcurrentUnGraph, GraphTrait};
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::invariants::{InvariantChecker, Violation};
+
+    fn tree(edges: &[(u32, u32)]) -> ConcurrentDiGraph<u32> {
+        let tree = ConcurrentDiGraph::new();
+        edges.iter().for_each(|&(a, b)| tree.add_edge(a, b));
+        tree
+    }
+
+    fn graph(edges: &[(u32, u32)]) -> ConcurrentUnGraph<u32> {
+        let graph = ConcurrentUnGraph::new();
+        edges.iter().for_each(|&(a, b)| graph.add_edge(a, b));
+        graph
+    }
+
+    #[test]
+    fn cracker_keeps_invariants() {
+        for spec in ["planted:500,1200,6", "zigzag:300", "comb:12,12", "rmat:9,2000"] {
+            let edges = spec.parse::<Generator>().unwrap().generate(10).unwrap().edges;
+
+            for variant in Variant::ALL {
+                let mut checker = InvariantChecker::new(&edges);
+                checker.fail_fast = false;
+                variant.crack_observed(&variant.graph(&edges), &mut checker);
+
+                assert_eq!(checker.violations, vec![], "{spec} {}", variant.name());
+            }
+        }
+    }
+
+    #[test]
+    fn violations() {
+        // {1, 2, 3} {4, 5}
+        let checker = InvariantChecker::new(&[(1, 2), (2, 3), (4, 5)]);
+        let empty = graph(&[]);
+
+        assert_eq!(checker.check(1, &empty, &tree(&[(1, 2), (1, 3), (4, 5)])), Ok(()));
+        assert_eq!(checker.check(1, &graph(&[(1, 3)]), &tree(&[(1, 2), (5, 4)])), Ok(()));
+
+        assert_eq!(
+            checker.check(2, &empty, &tree(&[(1, 3), (2, 3), (4, 5)])),
+            Err(Violation::Parents { iteration: 2, node: 3, parents: vec![1, 2] })
+        );
+        assert_eq!(
+            checker.check(3, &empty, &tree(&[(1, 2), (2, 3), (3, 1)])),
+            Err(Violation::Cycle { iteration: 3, from: 3, to: 1 })
+        );
+        assert_eq!(
+            checker.check(4, &empty, &tree(&[(1, 2), (4, 5)])),
+            Err(Violation::Connectivity { iteration: 4, a: 1, b: 3, in_input: true })
+        );
+        assert_eq!(
+            checker.check(5, &graph(&[(3, 4)]), &tree(&[(1, 2), (1, 3), (4, 5)])),
+            Err(Violation::Connectivity { iteration: 5, a: 1, b: 4, in_input: false })
+        );
+
+        // nodes missing from the input are reported, not looked up blindly
+        assert_eq!(
+            checker.check(6, &empty, &tree(&[(1, 2), (1, 3), (4, 5), (4, 9)])),
+            Err(Violation::UnknownNode { iteration: 6, node: 9 })
+        );
+        assert_eq!(
+            checker.check(7, &graph(&[(8, 1)]), &tree(&[(1, 2), (1, 3), (4, 5)])),
+            Err(Violation::UnknownNode { iteration: 7, node: 8 })
+        );
+        assert_eq!(Violation::UnknownNode { iteration: 7, node: 8 }.to_string(), "iteration 7: 8 is not a node of G");
+    }
+
+    #[test]
+    #[should_panic(expected = "invariant violated at iteration 1: 1 and 3 are connected in G but not in G_t ∪ T")]
+    fn fail_fast() {
+        use graph_example::cracker::Observer;
+
+        let mut checker = InvariantChecker::new(&[(1, 2), (2, 3)]);
+        Observer::<ConcurrentUnGraph<u32>, u32>::pruning(&mut checker, 1, &graph(&[]), &tree(&[(1, 2)]), Default::default());
+    }
+}
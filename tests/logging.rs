@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use graph_example::cracker::Variant;
+    use graph_example::logging::{filter, level};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    /// Collects the formatted events
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn logs(verbosity: usize) -> String {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_env_filter(level(verbosity))
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            Variant::Ep.run::<u32>(&[(1, 2), (2, 3), (4, 5)]);
+        });
+
+        let logs = buffer.0.lock().unwrap();
+        String::from_utf8(logs.clone()).unwrap()
+    }
+
+    #[test]
+    fn levels() {
+        assert_eq!(level(0), "warn");
+        assert_eq!(level(1), "info");
+        assert_eq!(level(2), "debug");
+        assert_eq!(level(5), "trace");
+
+        if std::env::var("RUST_LOG").is_err() {
+            assert_eq!(filter(1).to_string(), "info");
+        }
+    }
+
+    #[test]
+    fn iteration_and_phase_events() {
+        assert!(logs(0).is_empty());
+
+        let info = logs(1);
+        assert!(info.contains("cracker done"));
+        assert!(!info.contains("min_selection"));
+
+        let debug = logs(2);
+        assert!(debug.contains("iteration{it=1 active_nodes=5}"));
+        assert!(debug.contains("phase=\"min_selection\""));
+        assert!(debug.contains("phase=\"pruning\""));
+        assert!(debug.contains("phase=\"seed_propagation\""));
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::memory::{alloc_stats, is_counting, CountingAllocator, MemoryProfile};
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn counting_allocator() {
+        let before = alloc_stats();
+        let v: Vec<u64> = Vec::with_capacity(1 << 16);
+        let after = alloc_stats();
+
+        assert!(is_counting());
+        assert!(after.allocations > before.allocations);
+        assert!(after.allocated - before.allocated >= 8 << 16);
+        assert!(after.peak >= 8 << 16);
+        drop(v);
+    }
+
+    #[test]
+    fn one_phase_per_hook() {
+        let edges = "planted:300,600,3".parse::<Generator>().unwrap().generate(1).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let mut memory = MemoryProfile::start();
+            let graph = variant.graph(&edges);
+            memory.phase("load");
+
+            let res = variant.crack_observed(&graph, &mut memory);
+
+            let names: Vec<&str> = memory.phases.iter().map(|p| p.phase.as_str()).collect();
+            assert_eq!(names.len(), 2 + 2 * res.iterations, "{}", variant.name());
+            assert_eq!(names[0], "load");
+            assert_eq!(names[1], "min_selection_1");
+            assert_eq!(names[2], "pruning_1");
+            assert_eq!(*names.last().unwrap(), "seed_propagation");
+
+            assert_eq!(memory.matching("min_selection_").count(), res.iterations);
+            assert!(memory.phases[0].allocations > 0);
+            assert!(memory.peak_bytes() >= memory.phases[0].live_bytes);
+            assert_eq!(memory.allocations(), memory.phases.iter().map(|p| p.allocations).sum::<u64>());
+
+            // no /proc reads inside the iterations
+            assert!(memory.matching("min_selection_").chain(memory.matching("pruning_")).all(|p| p.rss_bytes.is_none()));
+        }
+    }
+}
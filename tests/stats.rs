@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::stats::{write_stats, StatsCollector, StatsReport};
+
+    type V = u32;
+
+    fn edges(spec: &str) -> Vec<(V, V)> {
+        spec.parse::<Generator>().unwrap().generate(1).unwrap().edges
+    }
+
+    #[test]
+    fn one_record_per_iteration() {
+        let edges = edges("planted:300,600,3");
+
+        for variant in Variant::ALL {
+            let graph = variant.graph(&edges);
+            let mut stats = StatsCollector::default();
+            let res = variant.crack_observed(&graph, &mut stats);
+
+            let it = &stats.iterations;
+            assert_eq!(it.len(), res.iterations);
+            assert_eq!(it[0].active_nodes, 300);
+            assert!(it.windows(2).all(|w| w[1].iteration == w[0].iteration + 1));
+            assert!(it.windows(2).all(|w| w[1].active_nodes < w[0].active_nodes));
+
+            // every node but the seeds is deactivated exactly once
+            let deactivated: usize = it.iter().map(|s| s.deactivated).sum();
+            assert_eq!(deactivated, 300 - 3, "{}", variant.name());
+            assert_eq!(it.last().unwrap().tree_edges, deactivated);
+            assert_eq!(it.last().unwrap().tree_nodes, 300);
+        }
+    }
+
+    #[test]
+    fn stats_json() {
+        let graph = Variant::Ep.graph(&edges("path:64"));
+        let mut stats = StatsCollector::default();
+        Variant::Ep.crack_observed(&graph, &mut stats);
+
+        let file = std::env::temp_dir().join(format!("stats_test_{}.json", std::process::id()));
+        let file = file.to_str().unwrap();
+        let report = StatsReport { file: "path:64", variant: "ep", num_threads: 1, iterations: &stats.iterations };
+        write_stats(file, &report).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(file).unwrap()).unwrap();
+        std::fs::remove_file(file).unwrap();
+
+        assert_eq!(json["variant"], "ep");
+        assert_eq!(json["iterations"].as_array().unwrap().len(), stats.iterations.len());
+        assert_eq!(json["iterations"][0]["active_nodes"], 64);
+    }
+}
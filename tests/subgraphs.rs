@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::subgraphs::{extract, giant, select, write_components, write_giant, Subgraph};
+    use io_util::prelude::{read_from_file, GraphFormat};
+
+    use std::{env, fs};
+
+    use dashmap::DashMap;
+
+    #[test]
+    fn select_largest_first() {
+        let sizes: DashMap<u32, usize> = [(1, 5), (2, 1), (3, 7), (4, 5), (5, 2)].into_iter().collect();
+
+        assert_eq!(select(&sizes, 1, None), vec![(3, 7), (1, 5), (4, 5), (5, 2), (2, 1)]);
+        assert_eq!(select(&sizes, 2, Some(3)), vec![(3, 7), (1, 5), (4, 5)]);
+        assert_eq!(select(&sizes, 8, None), vec![]);
+    }
+
+    #[test]
+    fn partition_edges() {
+        let edges = "planted:400,900,5".parse::<Generator>().unwrap().generate(2).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let graph = variant.graph(&edges);
+            let res = variant.crack(&graph);
+
+            let selected: Vec<u32> = select(&res.component_sizes(), 1, None).into_iter().map(|(s, _)| s).collect();
+            let subgraphs = extract(&graph, &res.seeds, &selected);
+
+            assert_eq!(subgraphs.len(), 5, "{}", variant.name());
+            assert_eq!(subgraphs.iter().map(|s| s.nodes.len()).sum::<usize>(), 400);
+            assert_eq!(subgraphs.iter().map(|s| s.edges.len()).sum::<usize>(), edges.len());
+            assert!(subgraphs.windows(2).all(|w| w[0].nodes.len() >= w[1].nodes.len()));
+
+            for subgraph in &subgraphs {
+                let seed = *res.seeds.get(&subgraph.nodes[0]).unwrap();
+                assert_eq!(seed, subgraph.seed);
+                assert!(subgraph.edges.iter().all(|(a, b)| a < b && subgraph.nodes.binary_search(b).is_ok()));
+            }
+
+            // only the selected components are materialized
+            let top = extract(&graph, &res.seeds, &selected[..1]);
+            assert_eq!(top, subgraphs[..1]);
+        }
+    }
+
+    #[test]
+    fn giant_component() {
+        let edges = "planted:400,900,5".parse::<Generator>().unwrap().generate(4).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let graph = variant.graph(&edges);
+            let res = variant.crack(&graph);
+
+            let giant = giant(&graph, &res.seeds, &res.component_sizes()).unwrap();
+            assert_eq!(giant.nodes.len(), res.largest_component(), "{}", variant.name());
+            assert!(giant.nodes.iter().all(|v| *res.seeds.get(v).unwrap() == giant.seed));
+        }
+
+        let graph = Variant::Ep.graph::<u32>(&[]);
+        let res = Variant::Ep.crack(&graph);
+        assert_eq!(giant(&graph, &res.seeds, &res.component_sizes()), None);
+    }
+
+    #[test]
+    fn relabelled() {
+        let subgraph = Subgraph { seed: 3, nodes: vec![3, 10, 42], edges: vec![(3, 10), (10, 42)] };
+        assert_eq!(subgraph.relabelled(), vec![(1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn write_files() {
+        let dir = env::temp_dir().join(format!("subgraphs_{}", std::process::id()));
+        let subgraphs = vec![
+            Subgraph { seed: 5, nodes: vec![5, 7, 9], edges: vec![(5, 7), (7, 9)] },
+            Subgraph { seed: 1, nodes: vec![1, 2], edges: vec![(1, 2)] },
+        ];
+
+        write_components(&dir, &subgraphs, GraphFormat::MatrixMarket).unwrap();
+
+        let index = fs::read_to_string(dir.join("index.csv")).unwrap();
+        assert_eq!(index, "file,seed,nodes,edges\ncomponent_0.mtx,5,3,2\ncomponent_1.mtx,1,2,1\n");
+
+        let first = read_from_file::<u32>(dir.join("component_0.mtx").to_str().unwrap()).unwrap();
+        assert_eq!(first, vec![(1, 2), (2, 3)]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn write_giant_files() {
+        let dir = env::temp_dir().join(format!("giant_{}", std::process::id()));
+        let giant = Subgraph { seed: 5, nodes: vec![5, 7, 9], edges: vec![(5, 7), (7, 9)] };
+
+        write_giant(&dir, &giant, GraphFormat::EdgeList).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join("giant.nodes")).unwrap(), "5\n7\n9\n");
+        assert_eq!(fs::read_to_string(dir.join("giant.el")).unwrap(), "5 7\n7 9\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use concurrent_graph::GraphTrait;
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::trace::TraceWriter;
+
+    use io_util::prelude::read_from_file;
+
+    use std::{env, fs};
+
+    #[test]
+    fn trace_every_iteration() {
+        let edges = "planted:300,700,4".parse::<Generator>().unwrap().generate(9).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let dir = env::temp_dir().join(format!("trace_{}_{}", variant.name(), std::process::id()));
+            let mut trace = TraceWriter::new(&dir).unwrap();
+
+            let res = variant.crack_observed(&variant.graph(&edges), &mut trace);
+            let rows = trace.rows.clone();
+            trace.finish().unwrap();
+
+            assert_eq!(rows.len(), res.iterations, "{}", variant.name());
+            assert_eq!(rows[0].gt_nodes, 300);
+            assert_eq!(rows.last().unwrap().next_nodes, 0);
+            assert_eq!(rows.last().unwrap().tree_edges, 300 - 4);
+
+            // every file loads back as an edge list
+            for it in 1..=res.iterations {
+                for graph in ["g", "h", "t"] {
+                    let path = dir.join(format!("{graph}_{it}.el"));
+                    assert!(read_from_file::<u32>(path.to_str().unwrap()).is_ok(), "{graph}_{it}");
+                }
+            }
+
+            // G_1 is the input graph
+            let g_1 = read_from_file::<u32>(dir.join("g_1.el").to_str().unwrap()).unwrap();
+            let expected = if variant == Variant::EpOs { 2 * edges.len() } else { edges.len() };
+            assert_eq!(g_1.len(), expected);
+            assert!(g_1.contains(&(edges[0].0.min(edges[0].1), edges[0].0.max(edges[0].1))));
+
+            // the last T loads back as the propagation tree, and gives the same components
+            let last = read_from_file::<u32>(dir.join(format!("t_{}.el", res.iterations)).to_str().unwrap()).unwrap();
+            assert_eq!(last.len(), res.tree.edge_count());
+            assert!(last.iter().all(|&(p, u)| res.tree.contains_edge(p, u)));
+            let reloaded = Variant::Ep.run(&last);
+            assert_eq!(reloaded.num_components(), res.num_components());
+            assert_eq!(reloaded.largest_component(), res.largest_component());
+
+            let summary = fs::read_to_string(dir.join("summary.csv")).unwrap();
+            assert_eq!(summary.lines().count(), res.iterations + 1);
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+}
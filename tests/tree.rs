@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::generators::Generator;
+    use graph_example::tree::{parent_array, write_dot, write_parents, TreeStats};
+    use io_util::prelude::read_from_file;
+
+    #[test]
+    fn parents_of_example() {
+        let edges = read_from_file::<u32>("files/example.mtx").unwrap();
+        let res = Variant::Ep.run(&edges);
+        let parents = parent_array(&res.tree);
+
+        assert_eq!(parents.len(), 11);
+        assert!(parents.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // following the parents leads to the seed
+        for &(v, _) in &parents {
+            let mut current = v;
+            while let Ok(i) = parents.binary_search_by_key(&current, |&(n, _)| n) {
+                if parents[i].1 == current {
+                    break;
+                }
+                current = parents[i].1;
+            }
+            assert_eq!(current, *res.seeds.get(&v).unwrap());
+        }
+
+        let mut out = Vec::new();
+        write_parents(&mut out, &parents[..2]).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), format!("0 0\n1 {}\n", parents[1].1));
+    }
+
+    #[test]
+    fn dot() {
+        let mut out = Vec::new();
+        write_dot(&mut out, &[(1, 1), (2, 1), (3, 2), (7, 7)]).unwrap();
+
+        let expected = "digraph T {\n  1 [shape=doublecircle];\n  7 [shape=doublecircle];\n  1 -> 2;\n  2 -> 3;\n}\n";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn stats() {
+        let edges = "planted:1000,2500,12".parse::<Generator>().unwrap().generate(8).unwrap().edges;
+
+        for variant in Variant::ALL {
+            let res = variant.run(&edges);
+            let stats = TreeStats::new(&res);
+
+            assert_eq!(stats.nodes, 1000, "{}", variant.name());
+            assert_eq!(stats.roots, 12);
+            assert_eq!(stats.depth, res.tree_depth());
+            assert_eq!(stats.depths.iter().map(|d| d.1).sum::<usize>(), 1000);
+            assert_eq!(stats.depths[0], (0, 12));
+
+            // every node but the roots is somebody's child
+            let children: usize = stats.fan_out.iter().map(|(k, n)| k * n).sum();
+            assert_eq!(children, 1000 - 12);
+            assert_eq!(stats.fan_out[0], (0, stats.leaves));
+            assert_eq!(stats.max_fan_out, stats.fan_out.last().unwrap().0);
+        }
+    }
+}
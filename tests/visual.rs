@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use concurrent_graph::GraphTrait;
+    use graph_example::cracker::Variant;
+    use graph_example::visual::{render_frame, render_html, FrameRecorder, Layout};
+    use io_util::prelude::read_from_file;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn frames_of_example() {
+        let edges = read_from_file::<u32>("files/example.mtx").unwrap();
+        let mut nodes: Vec<u32> = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+
+        for variant in Variant::ALL {
+            let mut recorder = FrameRecorder::default();
+            let res = variant.crack_observed(&variant.graph(&edges), &mut recorder);
+
+            assert_eq!(recorder.frames.len(), res.iterations, "{}", variant.name());
+            assert_eq!(recorder.frames[0].active, nodes);
+            assert!(recorder.frames.last().unwrap().next.is_empty());
+            assert_eq!(recorder.frames.last().unwrap().tree.len(), res.tree.edge_count());
+            assert_eq!(recorder.seeds.len(), nodes.len());
+
+            // every node of T is deactivated exactly once
+            let deactivated: Vec<u32> = recorder.frames.iter().flat_map(|f| f.deactivated.clone()).collect();
+            assert_eq!(deactivated.len(), res.tree.edge_count());
+            assert_eq!(deactivated.iter().collect::<HashSet<_>>().len(), deactivated.len());
+
+            let layout = Layout::circle(&nodes);
+            let svg = render_frame(&recorder.frames[0], &layout, &nodes);
+            assert!(svg.starts_with("<svg") && svg.trim_end().ends_with("</svg>"));
+            assert_eq!(svg.matches(r#"class="node""#).count(), 4 * nodes.len());
+
+            // only local assets
+            let html = render_html("example.mtx", &recorder, &layout, &nodes);
+            assert_eq!(html.matches("<svg").count(), res.iterations + 1);
+            assert!(!html.contains("src=") && !html.contains("<link"));
+        }
+    }
+}
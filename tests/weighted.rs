@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use graph_example::cracker::Variant;
+    use graph_example::weighted::{filter_edges, min_weight, nodes};
+    use io_util::prelude::{read_from_file, read_weighted_from_file};
+
+    #[test]
+    fn read_weights() {
+        let weighted = read_weighted_from_file::<u32, f64>("files/example_weighted.mtx").unwrap();
+        let pattern = read_from_file::<u32>("files/example_weighted.mtx").unwrap();
+
+        assert_eq!(weighted.len(), 10);
+        assert_eq!(weighted[0], (0, 1, 0.9));
+        assert_eq!(weighted.iter().map(|&(a, b, _)| (a, b)).collect::<Vec<_>>(), pattern);
+
+        // pattern files have no value column
+        assert!(read_weighted_from_file::<u32, f64>("files/example.mtx").is_err());
+        assert!(read_weighted_from_file::<u32, f64>("files/missing.mtx").is_err());
+
+        // NaN and infinite values are rejected rather than sorted somewhere
+        let path = std::env::temp_dir().join(format!("non_finite_{}.txt", std::process::id()));
+        for value in ["nan", "inf", "-inf", "NaN"] {
+            std::fs::write(&path, format!("0 1 0.5\n1 2 {value}\n2 3 0.9\n")).unwrap();
+            let err = read_weighted_from_file::<u32, f64>(path.to_str().unwrap()).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData, "{value}");
+            assert!(err.to_string().contains(":2:"), "{err}");
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn threshold_components() {
+        let weighted = read_weighted_from_file::<u32, f64>("files/example_weighted.mtx").unwrap();
+
+        assert_eq!(min_weight(&weighted, 0.0).len(), 10);
+        assert_eq!(min_weight(&weighted, 0.8), vec![(0, 1), (2, 4), (3, 6), (5, 8)]);
+        assert_eq!(filter_edges(&weighted, |w| w < 0.5), vec![(1, 2), (3, 4)]);
+
+        // stranded nodes stay in the graph as singletons: all 11 nodes at every threshold
+        let nodes = nodes(&weighted);
+        assert_eq!(nodes, (0..11).collect::<Vec<u32>>());
+
+        for (tau, components, largest) in [(0.0, 2, 9), (0.5, 3, 7), (0.8, 7, 2), (1.0, 11, 1)] {
+            for variant in Variant::ALL {
+                let graph = variant.graph_with_nodes(&min_weight(&weighted, tau), &nodes);
+                let res = variant.crack(&graph);
+                assert_eq!(res.num_components(), components, "{} at {tau}", variant.name());
+                assert_eq!(res.largest_component(), largest, "{} at {tau}", variant.name());
+                assert_eq!(res.seeds.len(), 11, "{} at {tau}", variant.name());
+            }
+        }
+    }
+}